@@ -0,0 +1,490 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use super::error::Endpoint;
+use super::http::{HttpClient, HttpResponse};
+use super::time::now_unix;
+
+const MINUTE: i64 = 60;
+const DAY: i64 = 24 * 60 * MINUTE;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freshness {
+    Fresh,
+    Stale,
+    Expired,
+}
+
+// Час життя відповіді, секунди: до fresh_for — віддаємо без мережі, до stale_for —
+// віддаємо одразу й оновлюємо у фоні, старіше — лише новий запит
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePolicy {
+    pub fresh_for: i64,
+    pub stale_for: i64,
+}
+
+impl CachePolicy {
+    pub fn for_endpoint(endpoint: Endpoint) -> Self {
+        match endpoint {
+            // Назви й координати місць не змінюються
+            Endpoint::Geocoding | Endpoint::ReverseGeocoding => Self {
+                fresh_for: DAY,
+                stale_for: 7 * DAY,
+            },
+            // «Дані оновлюються кожні 15 хвилин»: навіть застаріле не старше 15 хв
+            Endpoint::Current | Endpoint::Forecast => Self {
+                fresh_for: 10 * MINUTE,
+                stale_for: 15 * MINUTE,
+            },
+            // RainViewer оновлює кадри кожні 10 хв і тримає лише ~2 год минулих:
+            // шлях зі старого переліку вказує на тайли, яких уже немає
+            Endpoint::Radar => Self {
+                fresh_for: 5 * MINUTE,
+                stale_for: 10 * MINUTE,
+            },
+        }
+    }
+
+    pub fn freshness(self, age_secs: i64) -> Freshness {
+        match age_secs {
+            // Запис «з майбутнього» — годинник переводили; не довіряємо йому
+            ..=-1 => Freshness::Expired,
+            age if age < self.fresh_for => Freshness::Fresh,
+            age if age < self.stale_for => Freshness::Stale,
+            _ => Freshness::Expired,
+        }
+    }
+}
+
+// Запит без відомого endpoint-а живе так само коротко, як погода
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self::for_endpoint(Endpoint::Forecast)
+    }
+}
+
+// Тіло успішної відповіді і момент її отримання, секунди UTC
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub stored_at: i64,
+    pub body: String,
+}
+
+impl CacheEntry {
+    pub fn freshness(&self, now: i64, policy: CachePolicy) -> Freshness {
+        policy.freshness(now - self.stored_at)
+    }
+}
+
+// Сховище відповідей за ключем — повною адресою запиту, тобто координатами,
+// полями і тривалістю прогнозу разом
+pub trait CacheStore {
+    fn load(&self, key: &str) -> Option<CacheEntry>;
+    fn save(&self, key: &str, entry: &CacheEntry);
+    fn remove(&self, key: &str);
+}
+
+// Кеш у пам'яті на час роботи процесу чи вкладки
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    entries: Rc<RefCell<HashMap<String, CacheEntry>>>,
+}
+
+impl CacheStore for MemoryStore {
+    fn load(&self, key: &str) -> Option<CacheEntry> {
+        self.entries.borrow().get(key).cloned()
+    }
+
+    fn save(&self, key: &str, entry: &CacheEntry) {
+        self.entries.borrow_mut().insert(key.to_string(), entry.clone());
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.borrow_mut().remove(key);
+    }
+}
+
+// Кеш у localStorage: переживає перезавантаження сторінки
+#[cfg(feature = "web")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalStore;
+
+#[cfg(feature = "web")]
+const LOCAL_PREFIX: &str = "weather.cache:";
+
+#[cfg(feature = "web")]
+impl LocalStore {
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok().flatten()
+    }
+
+    // Прибирає всі записи кешу, не чіпаючи обране, історію й налаштування
+    fn clear(storage: &web_sys::Storage) {
+        let keys: Vec<String> = (0..storage.length().unwrap_or(0))
+            .filter_map(|idx| storage.key(idx).ok().flatten())
+            .filter(|key| key.starts_with(LOCAL_PREFIX))
+            .collect();
+        for key in keys {
+            let _ = storage.remove_item(&key);
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+impl CacheStore for LocalStore {
+    fn load(&self, key: &str) -> Option<CacheEntry> {
+        let raw = Self::storage()?.get_item(&format!("{}{}", LOCAL_PREFIX, key)).ok().flatten()?;
+        serde_json::from_str(&raw).ok()
+    }
+
+    fn save(&self, key: &str, entry: &CacheEntry) {
+        let (Some(storage), Ok(raw)) = (Self::storage(), serde_json::to_string(entry)) else {
+            return;
+        };
+        let key = format!("{}{}", LOCAL_PREFIX, key);
+        // Переповнена квота: звільняємо місце від старих відповідей і пробуємо ще раз
+        if storage.set_item(&key, &raw).is_err() {
+            Self::clear(&storage);
+            let _ = storage.set_item(&key, &raw);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        if let Some(storage) = Self::storage() {
+            let _ = storage.remove_item(&format!("{}{}", LOCAL_PREFIX, key));
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+pub type DefaultStore = LocalStore;
+
+#[cfg(not(feature = "web"))]
+pub type DefaultStore = MemoryStore;
+
+// Транспорт із кешем поверх іншого HttpClient (stale-while-revalidate):
+// свіжа відповідь повертається без мережі; застаріла — одразу, а нова
+// завантажується у фоні для наступного запиту. Час життя залежить від
+// endpoint-а (CachePolicy). Кешуються лише відповіді 2xx.
+// Без запускача фонових задач (нативно) застаріле оновлюється перед
+// відповіддю, а при мережевій помилці повертається застаріле
+#[derive(Debug, Clone)]
+pub struct CachedClient<C, S = DefaultStore> {
+    http: C,
+    store: S,
+    background: Option<Spawner>,
+    // Адреси, що вже оновлюються у фоні: серія застарілих читань дає один запит
+    refreshing: Rc<RefCell<HashSet<String>>>,
+}
+
+// Запускає фонове оновлення кешу, не чекаючи на нього
+pub type Spawner = fn(Pin<Box<dyn Future<Output = ()>>>);
+
+#[cfg(target_arch = "wasm32")]
+fn spawn_in_browser(task: Pin<Box<dyn Future<Output = ()>>>) {
+    wasm_bindgen_futures::spawn_local(task);
+}
+
+// У браузері є spawn_local; нативно рантайм невідомий, тож фонових задач немає
+fn default_spawner() -> Option<Spawner> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        Some(spawn_in_browser)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+impl<C, S> CachedClient<C, S> {
+    pub fn new(http: C, store: S) -> Self {
+        Self {
+            http,
+            store,
+            background: default_spawner(),
+            refreshing: Rc::default(),
+        }
+    }
+
+    // Інший запускач фонових оновлень (наприклад, tokio::task::spawn_local) або None
+    pub fn with_background(mut self, background: Option<Spawner>) -> Self {
+        self.background = background;
+        self
+    }
+}
+
+impl<C: Default, S: Default> Default for CachedClient<C, S> {
+    fn default() -> Self {
+        Self::new(C::default(), S::default())
+    }
+}
+
+impl<C: HttpClient, S: CacheStore> CachedClient<C, S> {
+    async fn refresh(http: &C, store: &S, url: &str) -> Result<HttpResponse, String> {
+        let response = http.get(url).await?;
+        if response.ok() {
+            store.save(
+                url,
+                &CacheEntry {
+                    stored_at: now_unix(),
+                    body: response.body.clone(),
+                },
+            );
+        }
+        Ok(response)
+    }
+}
+
+impl<C, S> HttpClient for CachedClient<C, S>
+where
+    C: HttpClient + Clone + 'static,
+    S: CacheStore + Clone + 'static,
+{
+    async fn get(&self, url: &str) -> Result<HttpResponse, String> {
+        self.get_with(url, CachePolicy::default()).await
+    }
+
+    async fn get_from(&self, url: &str, endpoint: Endpoint) -> Result<HttpResponse, String> {
+        self.get_with(url, CachePolicy::for_endpoint(endpoint)).await
+    }
+}
+
+impl<C, S> CachedClient<C, S>
+where
+    C: HttpClient + Clone + 'static,
+    S: CacheStore + Clone + 'static,
+{
+    async fn get_with(&self, url: &str, policy: CachePolicy) -> Result<HttpResponse, String> {
+        let cached = self.store.load(url);
+        let freshness = cached.as_ref().map(|entry| entry.freshness(now_unix(), policy));
+        let from_cache = |entry: CacheEntry| HttpResponse {
+            status: 200,
+            body: entry.body,
+        };
+
+        match (cached, freshness, self.background) {
+            (Some(entry), Some(Freshness::Fresh), _) => return Ok(from_cache(entry)),
+            (Some(entry), Some(Freshness::Stale), Some(spawn)) => {
+                if self.refreshing.borrow_mut().insert(url.to_string()) {
+                    let (http, store, url) = (self.http.clone(), self.store.clone(), url.to_string());
+                    let refreshing = self.refreshing.clone();
+                    spawn(Box::pin(async move {
+                        let _ = Self::refresh(&http, &store, &url).await;
+                        refreshing.borrow_mut().remove(&url);
+                    }));
+                }
+                return Ok(from_cache(entry));
+            }
+            (Some(entry), Some(Freshness::Stale), None) => {
+                return Self::refresh(&self.http, &self.store, url)
+                    .await
+                    .or_else(|_| Ok(from_cache(entry)));
+            }
+            (Some(_), _, _) => self.store.remove(url),
+            (None, _, _) => {}
+        }
+
+        Self::refresh(&self.http, &self.store, url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use super::*;
+
+    const URL: &str = "https://example.test/forecast?latitude=49.44&longitude=32.06";
+
+    // Транспорт-заглушка: рахує запити, відповідає одразу
+    #[derive(Clone, Default)]
+    struct FakeHttp {
+        calls: Rc<Cell<u32>>,
+        offline: bool,
+    }
+
+    impl HttpClient for FakeHttp {
+        async fn get(&self, _url: &str) -> Result<HttpResponse, String> {
+            self.calls.set(self.calls.get() + 1);
+            if self.offline {
+                return Err("немає мережі".to_string());
+            }
+            Ok(HttpResponse {
+                status: 200,
+                body: "нове".to_string(),
+            })
+        }
+    }
+
+    // Черга замість spawn_local: фонові оновлення виконуються, коли скаже тест
+    thread_local! {
+        static BACKGROUND: RefCell<Vec<Pin<Box<dyn Future<Output = ()>>>>> = RefCell::new(Vec::new());
+    }
+
+    fn queue(task: Pin<Box<dyn Future<Output = ()>>>) {
+        BACKGROUND.with(|tasks| tasks.borrow_mut().push(task));
+    }
+
+    fn run_background() -> usize {
+        let tasks = BACKGROUND.with(|tasks| tasks.take());
+        let count = tasks.len();
+        for task in tasks {
+            ready(task);
+        }
+        count
+    }
+
+    // Усі future тут готові з першого опитування
+    fn ready<T>(future: impl Future<Output = T>) -> T {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("future не завершився"),
+        }
+    }
+
+    fn cached_client(offline: bool, age: i64) -> (CachedClient<FakeHttp, MemoryStore>, Rc<Cell<u32>>) {
+        let http = FakeHttp { offline, ..FakeHttp::default() };
+        let calls = http.calls.clone();
+        let store = MemoryStore::default();
+        store.save(
+            URL,
+            &CacheEntry {
+                stored_at: now_unix() - age,
+                body: "старе".to_string(),
+            },
+        );
+        (CachedClient::new(http, store), calls)
+    }
+
+    #[test]
+    fn fresh_entry_skips_network() {
+        let (client, calls) = cached_client(false, 60);
+        assert_eq!(ready(client.get(URL)).unwrap().body, "старе");
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn stale_entry_is_served_at_once_and_refreshed_in_background() {
+        let (client, calls) = cached_client(false, CachePolicy::default().fresh_for + 60);
+        let client = client.with_background(Some(queue));
+
+        assert_eq!(ready(client.get(URL)).unwrap().body, "старе");
+        assert_eq!(calls.get(), 0);
+        assert_eq!(run_background(), 1);
+        assert_eq!(calls.get(), 1);
+        // Наступний запит отримує оновлене без мережі
+        assert_eq!(ready(client.get(URL)).unwrap().body, "нове");
+        assert_eq!(run_background(), 0);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn failed_background_refresh_keeps_stale_entry() {
+        let (client, calls) = cached_client(true, CachePolicy::default().fresh_for + 60);
+        let client = client.with_background(Some(queue));
+
+        assert_eq!(ready(client.get(URL)).unwrap().body, "старе");
+        assert_eq!(run_background(), 1);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(client.store.load(URL).map(|entry| entry.body).as_deref(), Some("старе"));
+    }
+
+    #[test]
+    fn repeated_stale_reads_share_one_background_refresh() {
+        let (client, calls) = cached_client(true, CachePolicy::default().fresh_for + 60);
+        let client = client.with_background(Some(queue));
+
+        for _ in 0..3 {
+            assert_eq!(ready(client.get(URL)).unwrap().body, "старе");
+        }
+        // Клон (інша картка порівняння) бачить те саме оновлення
+        assert_eq!(ready(client.clone().get(URL)).unwrap().body, "старе");
+        assert_eq!(run_background(), 1);
+        assert_eq!(calls.get(), 1);
+
+        // Оновлення завершилося невдало — наступне читання пробує знову
+        assert_eq!(ready(client.get(URL)).unwrap().body, "старе");
+        assert_eq!(run_background(), 1);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn without_background_stale_entry_is_refreshed_first() {
+        let (client, calls) = cached_client(false, CachePolicy::default().fresh_for + 60);
+        assert_eq!(ready(client.get(URL)).unwrap().body, "нове");
+        assert_eq!(calls.get(), 1);
+        // Оновлений запис знову свіжий
+        assert_eq!(ready(client.get(URL)).unwrap().body, "нове");
+        assert_eq!(calls.get(), 1);
+
+        let (client, _) = cached_client(true, CachePolicy::default().fresh_for + 60);
+        assert_eq!(ready(client.get(URL)).unwrap().body, "старе");
+    }
+
+    #[test]
+    fn expired_entry_is_not_served() {
+        let (client, _) = cached_client(true, CachePolicy::default().stale_for + 60);
+        assert!(ready(client.get(URL)).is_err());
+        assert_eq!(client.store.load(URL), None);
+    }
+
+    #[test]
+    fn freshness_follows_age() {
+        let policy = CachePolicy {
+            fresh_for: 600,
+            stale_for: 900,
+        };
+        assert_eq!(policy.freshness(0), Freshness::Fresh);
+        assert_eq!(policy.freshness(599), Freshness::Fresh);
+        assert_eq!(policy.freshness(600), Freshness::Stale);
+        assert_eq!(policy.freshness(899), Freshness::Stale);
+        assert_eq!(policy.freshness(900), Freshness::Expired);
+        assert_eq!(policy.freshness(-60), Freshness::Expired);
+    }
+
+    #[test]
+    fn weather_and_radar_are_never_served_old() {
+        // Підвал обіцяє оновлення кожні 15 хвилин
+        for endpoint in [Endpoint::Current, Endpoint::Forecast] {
+            assert!(CachePolicy::for_endpoint(endpoint).stale_for <= 15 * MINUTE, "{:?}", endpoint);
+        }
+        // RainViewer тримає минулі кадри лише близько 2 годин
+        let radar = CachePolicy::for_endpoint(Endpoint::Radar);
+        assert!(radar.stale_for <= 15 * MINUTE);
+        assert!(radar.fresh_for < radar.stale_for);
+    }
+
+    #[test]
+    fn endpoint_policy_reaches_the_cache() {
+        // 20 хвилин: геокодинг ще свіжий, радар уже прострочений
+        let (client, calls) = cached_client(true, 20 * MINUTE);
+        assert_eq!(ready(client.get_from(URL, Endpoint::Geocoding)).unwrap().body, "старе");
+        assert_eq!(calls.get(), 0);
+        assert!(ready(client.get_from(URL, Endpoint::Radar)).is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn memory_store_round_trip() {
+        let store = MemoryStore::default();
+        let entry = CacheEntry {
+            stored_at: 1_700_000_000,
+            body: "{}".to_string(),
+        };
+        store.save("https://example.test/?lat=1&lon=2", &entry);
+        // Клон ділить ті самі записи
+        assert_eq!(store.clone().load("https://example.test/?lat=1&lon=2"), Some(entry));
+        assert_eq!(store.load("https://example.test/?lat=1&lon=3"), None);
+        store.remove("https://example.test/?lat=1&lon=2");
+        assert_eq!(store.load("https://example.test/?lat=1&lon=2"), None);
+    }
+}
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Запит до Open-Meteo, під час якого сталася помилка
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Endpoint {
+    Geocoding,
+    ReverseGeocoding,
+    Current,
+    Forecast,
+    Radar,
+}
+
+// Типізована помилка шару API. Значення не містить готового тексту для
+// користувача — його формує Display (або UI сам вирішує, що показати)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WeatherError {
+    // Порожній пошуковий запит
+    EmptyQuery,
+    // Запит не дійшов до сервера (мережа, DNS, CORS)
+    Network { endpoint: Endpoint, message: String },
+    // Сервер відповів кодом, відмінним від 2xx
+    Http { endpoint: Endpoint, status: u16 },
+    // Відповідь не відповідає очікуваній схемі; path — шлях до поля, напр. "daily.sunrise[3]"
+    Decode { endpoint: Endpoint, path: String, message: String },
+    // Геокодер не знайшов жодного населеного пункту
+    CityNotFound { query: String },
+    // Браузер не надав координати (відмова в доступі, тайм-аут, немає API)
+    LocationUnavailable { message: String },
+    // Поточна погода є, але прогноз отримати не вдалося
+    PartialForecast(Box<WeatherError>),
+}
+
+impl WeatherError {
+    // Чи має сенс повторити запит (тимчасові збої мережі або сервера)
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            WeatherError::Network { .. } => true,
+            WeatherError::Http { status, .. } => *status == 429 || *status >= 500,
+            WeatherError::PartialForecast(inner) => inner.is_retryable(),
+            _ => false,
+        }
+    }
+
+    pub fn endpoint(&self) -> Option<Endpoint> {
+        match self {
+            WeatherError::Network { endpoint, .. }
+            | WeatherError::Http { endpoint, .. }
+            | WeatherError::Decode { endpoint, .. } => Some(*endpoint),
+            WeatherError::PartialForecast(inner) => inner.endpoint(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Endpoint::Geocoding => "geocoding",
+            Endpoint::ReverseGeocoding => "зворотного geocoding",
+            Endpoint::Current => "погоди",
+            Endpoint::Forecast => "прогнозу",
+            Endpoint::Radar => "радара опадів",
+        };
+        f.write_str(name)
+    }
+}
+
+// Українськомовне подання помилки для UI та логів
+impl fmt::Display for WeatherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeatherError::EmptyQuery => write!(f, "Будь ласка, введіть назву міста"),
+            WeatherError::Network { message, .. } => write!(f, "Помилка мережі: {}", message),
+            WeatherError::Http { endpoint, status } => write!(f, "Помилка {}: {}", endpoint, status),
+            WeatherError::Decode { endpoint, path, message } => {
+                write!(f, "Помилка парсингу {} ({}): {}", endpoint, path, message)
+            }
+            WeatherError::CityNotFound { query } => write!(f, "Місто «{}» не знайдено", query),
+            WeatherError::LocationUnavailable { message } => {
+                write!(f, "Не вдалося визначити місцезнаходження: {}", message)
+            }
+            WeatherError::PartialForecast(inner) => write!(f, "Прогноз недоступний: {}", inner),
+        }
+    }
+}
+
+impl std::error::Error for WeatherError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WeatherError::PartialForecast(inner) => Some(inner.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn http(status: u16) -> WeatherError {
+        WeatherError::Http {
+            endpoint: Endpoint::Forecast,
+            status,
+        }
+    }
+
+    #[test]
+    fn network_and_server_errors_are_retryable() {
+        let network = WeatherError::Network {
+            endpoint: Endpoint::Current,
+            message: "timeout".to_string(),
+        };
+
+        assert!(network.is_retryable());
+        assert!(http(500).is_retryable());
+        assert!(http(503).is_retryable());
+        assert!(http(429).is_retryable());
+    }
+
+    #[test]
+    fn client_and_data_errors_are_not_retryable() {
+        let decode = WeatherError::Decode {
+            endpoint: Endpoint::Forecast,
+            path: "daily.time".to_string(),
+            message: "missing field".to_string(),
+        };
+        let not_found = WeatherError::CityNotFound {
+            query: "Атлантида".to_string(),
+        };
+
+        for err in [http(400), http(404), decode, not_found, WeatherError::EmptyQuery] {
+            assert!(!err.is_retryable(), "{:?}", err);
+        }
+    }
+
+    #[test]
+    fn partial_forecast_unwraps_inner_error() {
+        let partial = WeatherError::PartialForecast(Box::new(http(502)));
+
+        assert_eq!(partial.endpoint(), Some(Endpoint::Forecast));
+        assert!(partial.is_retryable());
+        assert!(!WeatherError::PartialForecast(Box::new(http(404))).is_retryable());
+        assert_eq!(partial.to_string(), "Прогноз недоступний: Помилка прогнозу: 502");
+    }
+
+    #[test]
+    fn errors_without_request_have_no_endpoint() {
+        assert_eq!(WeatherError::EmptyQuery.endpoint(), None);
+        assert_eq!(WeatherError::CityNotFound { query: "X".to_string() }.endpoint(), None);
+        assert_eq!(http(500).endpoint(), Some(Endpoint::Forecast));
+    }
+}
@@ -0,0 +1,129 @@
+use super::error::Endpoint;
+
+// Сира HTTP-відповідь: код статусу і тіло
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn ok(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+// Транспорт для шару API. Помилка — текст мережевої помилки; HTTP-статуси,
+// відмінні від 2xx, повертаються як звичайна відповідь.
+// Futures у wasm не є Send, тому async fn без додаткових обмежень
+#[allow(async_fn_in_trait)]
+pub trait HttpClient {
+    async fn get(&self, url: &str) -> Result<HttpResponse, String>;
+
+    // Запит до відомого endpoint-а. Звичайні транспорти його не розрізняють,
+    // а CachedClient бере з нього час життя відповіді
+    async fn get_from(&self, url: &str, _endpoint: Endpoint) -> Result<HttpResponse, String> {
+        self.get(url).await
+    }
+}
+
+// Клієнт для браузера на основі fetch через gloo-net
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone, Default)]
+pub struct GlooClient {
+    // Сигнал AbortController: після abort() fetch переривається
+    abort: Option<web_sys::AbortSignal>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl HttpClient for GlooClient {
+    async fn get(&self, url: &str) -> Result<HttpResponse, String> {
+        let response = gloo_net::http::Request::get(url)
+            .abort_signal(self.abort.as_ref())
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let status = response.status();
+        let body = response.text().await.map_err(|e| e.to_string())?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+// Скасування запитів, надісланих через client(). У браузері abort() перериває
+// fetch; нативний UreqClient блокує потік до відповіді, тож там скасовувати нічого
+#[derive(Debug, Clone, Default)]
+pub struct AbortHandle {
+    #[cfg(target_arch = "wasm32")]
+    controller: Option<web_sys::AbortController>,
+}
+
+impl AbortHandle {
+    pub fn new() -> Self {
+        Self {
+            #[cfg(target_arch = "wasm32")]
+            controller: web_sys::AbortController::new().ok(),
+        }
+    }
+
+    pub fn abort(&self) {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(controller) = &self.controller {
+            controller.abort();
+        }
+    }
+
+    // Транспорт без кешу: скасовані й проміжні відповіді не варто зберігати
+    #[cfg(target_arch = "wasm32")]
+    pub fn client(&self) -> GlooClient {
+        GlooClient {
+            abort: self.controller.as_ref().map(web_sys::AbortController::signal),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn client(&self) -> UreqClient {
+        UreqClient::default()
+    }
+}
+
+// Блокуючий клієнт для нативних збірок (CLI, скрипти, тести). Його async fn
+// виконує запит синхронно і тримає потік до 15 с, тож в async-рантаймі
+// (tokio тощо) викликайте його через spawn_blocking або реалізуйте HttpClient
+// поверх асинхронного клієнта і передайте у WeatherClient::new
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(std::time::Duration::from_secs(15))
+                .build(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl HttpClient for UreqClient {
+    async fn get(&self, url: &str) -> Result<HttpResponse, String> {
+        let (status, response) = match self.agent.get(url).call() {
+            Ok(response) => (response.status(), response),
+            Err(ureq::Error::Status(status, response)) => (status, response),
+            Err(e) => return Err(e.to_string()),
+        };
+        let body = response.into_string().map_err(|e| e.to_string())?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+// У браузері відповіді кешуються, щоб пошук і чипи не тягнули ті самі дані знову
+#[cfg(target_arch = "wasm32")]
+pub type DefaultClient = super::cache::CachedClient<GlooClient>;
+
+// Нативно типовий транспорт блокує потік — див. UreqClient
+#[cfg(not(target_arch = "wasm32"))]
+pub type DefaultClient = UreqClient;
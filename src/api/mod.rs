@@ -1 +1,7 @@
+pub mod cache;
+pub mod error;
+pub mod http;
+pub mod time;
+pub mod units;
 pub mod weather;
+pub mod wmo;
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+// Місцевий час локації. Open-Meteo з timezone=auto повертає utc_offset_seconds,
+// тож час обчислюємо з UTC і зсуву, а не з годинника браузера
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+}
+
+impl LocalTime {
+    pub fn from_unix(unix_seconds: i64, utc_offset_seconds: i32) -> Self {
+        let local = unix_seconds + utc_offset_seconds as i64;
+        let (year, month, day) = civil_from_days(local.div_euclid(86_400));
+        let seconds = local.rem_euclid(86_400);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: (seconds / 3600) as u32,
+            minute: (seconds % 3600 / 60) as u32,
+        }
+    }
+
+    // "2024-01-15" — у форматі DailyForecast.time
+    pub fn date(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    // "07:05"
+    pub fn clock(&self) -> String {
+        format!("{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+// Поточний момент у секундах UTC: у браузері — Date.now(), нативно — системний годинник
+#[cfg(target_arch = "wasm32")]
+pub fn now_unix() -> i64 {
+    (js_sys::Date::now() / 1000.0).floor() as i64
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now_unix() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+// Дні від 1970-01-01 -> (рік, місяць, день) за пролептичним григоріанським календарем
+// (алгоритм civil_from_days Говарда Гіннанта)
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year as i32, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(unix_seconds: i64, utc_offset_seconds: i32) -> (String, String) {
+        let time = LocalTime::from_unix(unix_seconds, utc_offset_seconds);
+        (time.date(), time.clock())
+    }
+
+    #[test]
+    fn epoch_and_second_before_it() {
+        assert_eq!(local(0, 0), ("1970-01-01".to_string(), "00:00".to_string()));
+        assert_eq!(local(-1, 0), ("1969-12-31".to_string(), "23:59".to_string()));
+    }
+
+    #[test]
+    fn negative_offset_crosses_midnight_back_into_previous_year() {
+        // 2024-01-01T01:00Z у Нью-Йорку (UTC−5)
+        assert_eq!(local(1_704_070_800, -5 * 3600), ("2023-12-31".to_string(), "20:00".to_string()));
+    }
+
+    #[test]
+    fn positive_offset_crosses_midnight_into_leap_day() {
+        // 2024-02-28T22:00Z у Києві влітку (UTC+3)
+        assert_eq!(local(1_709_157_600, 3 * 3600), ("2024-02-29".to_string(), "01:00".to_string()));
+        assert_eq!(local(1_709_209_800, 0), ("2024-02-29".to_string(), "12:30".to_string()));
+    }
+
+    #[test]
+    fn leap_century_rules() {
+        // 2000 — високосний (ділиться на 400), 1900 — ні
+        assert_eq!(local(951_782_400, 0).0, "2000-02-29");
+        assert_eq!(local(-2_203_891_200, 0).0, "1900-03-01");
+        assert_eq!(local(-2_203_891_200 - 1, 0).0, "1900-02-28");
+    }
+
+    #[test]
+    fn dates_before_1970_with_offsets() {
+        // 1969-07-20T20:17Z
+        assert_eq!(local(-14_182_980, 0), ("1969-07-20".to_string(), "20:17".to_string()));
+        assert_eq!(local(-14_182_980, 5 * 3600 + 30 * 60), ("1969-07-21".to_string(), "01:47".to_string()));
+        assert_eq!(local(-14_182_980, -10 * 3600), ("1969-07-20".to_string(), "10:17".to_string()));
+    }
+}
@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+
+// WeatherData зберігає значення в одиницях Open-Meteo (°C, км/год, гПа);
+// перетворення в одиниці користувача відбувається лише під час відображення
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WindUnit {
+    #[default]
+    MetersPerSecond,
+    KilometersPerHour,
+    MilesPerHour,
+    Knots,
+    Beaufort,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PressureUnit {
+    Hectopascal,
+    #[default]
+    MillimetersOfMercury,
+    InchesOfMercury,
+}
+
+// Налаштування одиниць користувача. За замовчуванням — як у застосунку раніше: °C, м/с, мм рт. ст.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Units {
+    pub temperature: TemperatureUnit,
+    pub wind: WindUnit,
+    pub pressure: PressureUnit,
+}
+
+impl Units {
+    pub fn imperial() -> Self {
+        Self {
+            temperature: TemperatureUnit::Fahrenheit,
+            wind: WindUnit::MilesPerHour,
+            pressure: PressureUnit::InchesOfMercury,
+        }
+    }
+}
+
+impl TemperatureUnit {
+    pub const ALL: [TemperatureUnit; 2] = [TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit];
+
+    pub fn convert(self, celsius: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+        }
+    }
+
+    // "+5°" — для таблиць і карток, де одиниця вказана в заголовку
+    pub fn format(self, celsius: f64) -> String {
+        format!("{:+}°", self.convert(celsius).round() as i32)
+    }
+}
+
+impl WindUnit {
+    pub const ALL: [WindUnit; 5] = [
+        WindUnit::MetersPerSecond,
+        WindUnit::KilometersPerHour,
+        WindUnit::MilesPerHour,
+        WindUnit::Knots,
+        WindUnit::Beaufort,
+    ];
+
+    pub fn convert(self, kmh: f64) -> f64 {
+        match self {
+            WindUnit::MetersPerSecond => kmh / 3.6,
+            WindUnit::KilometersPerHour => kmh,
+            WindUnit::MilesPerHour => kmh / 1.609_344,
+            WindUnit::Knots => kmh / 1.852,
+            WindUnit::Beaufort => beaufort(kmh / 3.6) as f64,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WindUnit::MetersPerSecond => "м/с",
+            WindUnit::KilometersPerHour => "км/год",
+            WindUnit::MilesPerHour => "миль/год",
+            WindUnit::Knots => "вузли",
+            WindUnit::Beaufort => "бали",
+        }
+    }
+
+    pub fn format(self, kmh: f64) -> String {
+        format!("{:.0}", self.convert(kmh))
+    }
+}
+
+// Шкала Бофорта за верхніми межами швидкості в м/с
+fn beaufort(ms: f64) -> u8 {
+    const LIMITS: [f64; 12] = [0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7];
+    LIMITS.iter().position(|&limit| ms < limit).unwrap_or(LIMITS.len()) as u8
+}
+
+impl PressureUnit {
+    pub const ALL: [PressureUnit; 3] = [
+        PressureUnit::Hectopascal,
+        PressureUnit::MillimetersOfMercury,
+        PressureUnit::InchesOfMercury,
+    ];
+
+    pub fn convert(self, hpa: f64) -> f64 {
+        match self {
+            PressureUnit::Hectopascal => hpa,
+            PressureUnit::MillimetersOfMercury => hpa * 0.750_062,
+            PressureUnit::InchesOfMercury => hpa * 0.029_53,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PressureUnit::Hectopascal => "гПа",
+            PressureUnit::MillimetersOfMercury => "мм рт. ст.",
+            PressureUnit::InchesOfMercury => "дюйм рт. ст.",
+        }
+    }
+
+    pub fn format(self, hpa: f64) -> String {
+        match self {
+            PressureUnit::InchesOfMercury => format!("{:.2}", self.convert(hpa)),
+            _ => format!("{:.0}", self.convert(hpa)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(actual: f64, expected: f64) -> bool {
+        (actual - expected).abs() < 1e-3
+    }
+
+    #[test]
+    fn celsius_to_fahrenheit() {
+        let f = TemperatureUnit::Fahrenheit;
+
+        assert_eq!(f.convert(0.0), 32.0);
+        assert_eq!(f.convert(100.0), 212.0);
+        assert_eq!(f.convert(-40.0), -40.0);
+        assert_eq!(TemperatureUnit::Celsius.convert(-40.0), -40.0);
+        assert_eq!(f.format(-40.0), "-40°");
+        assert_eq!(f.format(0.0), "+32°");
+        assert_eq!(TemperatureUnit::Celsius.format(0.4), "+0°");
+    }
+
+    #[test]
+    fn wind_speed_conversions() {
+        assert!(close(WindUnit::MetersPerSecond.convert(36.0), 10.0));
+        assert!(close(WindUnit::MilesPerHour.convert(1.609_344), 1.0));
+        assert!(close(WindUnit::Knots.convert(1.852), 1.0));
+        assert_eq!(WindUnit::KilometersPerHour.convert(12.5), 12.5);
+        assert_eq!(WindUnit::MetersPerSecond.format(12.2), "3");
+        assert_eq!(WindUnit::MilesPerHour.format(100.0), "62");
+    }
+
+    #[test]
+    fn pressure_conversions_and_decimals() {
+        let hpa = 1013.25;
+
+        assert!(close(PressureUnit::MillimetersOfMercury.convert(hpa), 760.0));
+        assert!(close(PressureUnit::InchesOfMercury.convert(hpa), 29.921));
+        assert_eq!(PressureUnit::MillimetersOfMercury.format(hpa), "760");
+        assert_eq!(PressureUnit::InchesOfMercury.format(hpa), "29.92");
+        assert_eq!(PressureUnit::InchesOfMercury.format(1000.0), "29.53");
+        assert_eq!(PressureUnit::Hectopascal.format(hpa), "1013");
+    }
+
+    #[test]
+    fn beaufort_boundaries() {
+        // Верхня межа кожного бала, м/с: рівно на межі — вже наступний бал
+        let limits = [0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7];
+        for (force, &limit) in limits.iter().enumerate() {
+            assert_eq!(beaufort(limit - 0.01), force as u8, "{} м/с", limit - 0.01);
+            assert_eq!(beaufort(limit), force as u8 + 1, "{} м/с", limit);
+        }
+        assert_eq!(beaufort(0.0), 0);
+        assert_eq!(beaufort(60.0), 12);
+    }
+
+    #[test]
+    fn beaufort_from_kmh() {
+        assert_eq!(WindUnit::Beaufort.convert(0.0), 0.0);
+        // 8.0 м/с = 28.8 км/год — межа 4 і 5 балів
+        assert_eq!(WindUnit::Beaufort.convert(28.7), 4.0);
+        assert_eq!(WindUnit::Beaufort.convert(28.8), 5.0);
+        assert_eq!(WindUnit::Beaufort.format(118.0), "12");
+    }
+
+    #[test]
+    fn units_round_trip_through_json() {
+        let units = Units::imperial();
+        let json = serde_json::to_string(&units).expect("серіалізація");
+
+        assert_eq!(serde_json::from_str::<Units>(&json).ok(), Some(units));
+    }
+}
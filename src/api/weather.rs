@@ -1,8 +1,75 @@
-use serde::{Deserialize, Serialize};
-use gloo_net::http::Request;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::error::{Endpoint, WeatherError};
+use super::http::{AbortHandle, DefaultClient, HttpClient};
+use super::time::LocalTime;
+use super::wmo::WeatherCode;
 
 const GEOCODING_API: &str = "https://geocoding-api.open-meteo.com/v1/search";
 const WEATHER_API: &str = "https://api.open-meteo.com/v1/forecast";
+// Open-Meteo не має зворотного геокодингу; клієнтський endpoint BigDataCloud не потребує ключа
+const REVERSE_GEOCODING_API: &str = "https://api.bigdatacloud.net/data/reverse-geocode-client";
+// Шаблон адрес тайлів базової карти: {z}/{x}/{y} підставляє компонент карти
+const MAP_TILES: &str = "https://tile.openstreetmap.org/{z}/{x}/{y}.png";
+// Перелік кадрів радара опадів RainViewer
+const RADAR_API: &str = "https://api.rainviewer.com/public/weather-maps.json";
+
+// Скільки кандидатів повертає пошук населених пунктів
+pub const SEARCH_RESULTS: usize = 10;
+
+// Базові адреси API. Перевизначаються змінними оточення WEATHER_GEOCODING_API,
+// WEATHER_FORECAST_API, WEATHER_REVERSE_GEOCODING_API, WEATHER_MAP_TILES і
+// WEATHER_RADAR_API — під час виконання (нативно) або під час збірки (wasm), щоб CI
+// міг спрямувати весь крейт на локальний стаб. Карту без мережі розробляємо так:
+//   WEATHER_MAP_TILES=http://127.0.0.1:8081/tiles/{z}/{x}/{y}.png \
+//   WEATHER_RADAR_API=http://127.0.0.1:8081/weather-maps.json trunk serve
+// де weather-maps.json має host "http://127.0.0.1:8081" і кадри з path "/radar/<кадр>"
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiConfig {
+    pub geocoding_url: String,
+    pub weather_url: String,
+    pub reverse_geocoding_url: String,
+    pub map_tiles_url: String,
+    pub radar_url: String,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            geocoding_url: base_url("WEATHER_GEOCODING_API", option_env!("WEATHER_GEOCODING_API"), GEOCODING_API),
+            weather_url: base_url("WEATHER_FORECAST_API", option_env!("WEATHER_FORECAST_API"), WEATHER_API),
+            reverse_geocoding_url: base_url(
+                "WEATHER_REVERSE_GEOCODING_API",
+                option_env!("WEATHER_REVERSE_GEOCODING_API"),
+                REVERSE_GEOCODING_API,
+            ),
+            map_tiles_url: base_url("WEATHER_MAP_TILES", option_env!("WEATHER_MAP_TILES"), MAP_TILES),
+            radar_url: base_url("WEATHER_RADAR_API", option_env!("WEATHER_RADAR_API"), RADAR_API),
+        }
+    }
+}
+
+fn base_url(var: &str, compiled: Option<&str>, fallback: &str) -> String {
+    std::env::var(var)
+        .ok()
+        .or_else(|| compiled.map(str::to_string))
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+// Клієнт Open-Meteo поверх довільного транспорту. Нативний WeatherClient::default()
+// блокує потік на час кожного запиту (UreqClient): годиться для CLI і pollster,
+// а не для виклику з async-рантайму без spawn_blocking
+#[derive(Debug, Clone)]
+pub struct WeatherClient<C = DefaultClient> {
+    http: C,
+    config: ApiConfig,
+}
+
+impl Default for WeatherClient<DefaultClient> {
+    fn default() -> Self {
+        Self::new(DefaultClient::default(), ApiConfig::default())
+    }
+}
 
 // Структура для пошуку міста
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,13 +82,86 @@ pub struct GeoLocation {
     pub name: String,
     pub latitude: f64,
     pub longitude: f64,
+    // Для деяких територій Open-Meteo не повертає країну
+    #[serde(default)]
     pub country: String,
     pub admin1: Option<String>,
+    pub population: Option<u64>,
+}
+
+// Перелік кадрів радара RainViewer: тайли кадру — {host}{path}/256/{z}/{x}/{y}/...
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarMapsResponse {
+    pub host: String,
+    pub radar: RadarFrames,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarFrames {
+    #[serde(default)]
+    pub past: Vec<RadarFrame>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarFrame {
+    pub time: i64,
+    pub path: String,
+}
+
+// Відповідь зворотного геокодингу (BigDataCloud)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReverseGeocodingResponse {
+    #[serde(default)]
+    pub city: String,
+    #[serde(default)]
+    pub locality: String,
+    #[serde(default)]
+    pub principal_subdivision: String,
+    #[serde(default)]
+    pub country_name: String,
+}
+
+impl GeoLocation {
+    // Локація без назви: підпис "lat, lon"
+    pub fn from_coords(latitude: f64, longitude: f64) -> Self {
+        Self {
+            name: format!("{:.4}, {:.4}", latitude, longitude),
+            latitude,
+            longitude,
+            country: String::new(),
+            admin1: None,
+            population: None,
+        }
+    }
+
+    // "Черкаська область, Україна" — для розрізнення однойменних населених пунктів
+    pub fn region(&self) -> String {
+        join_region(self.admin1.as_deref(), &self.country)
+    }
+
+    // Те саме місце з точністю ~100 м (координати з різних джерел трохи відрізняються)
+    pub fn same_place(&self, other: &GeoLocation) -> bool {
+        (self.latitude - other.latitude).abs() < 1e-3 && (self.longitude - other.longitude).abs() < 1e-3
+    }
+}
+
+fn join_region(admin1: Option<&str>, country: &str) -> String {
+    admin1
+        .into_iter()
+        .chain((!country.is_empty()).then_some(country))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 // Структура погодних даних
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherResponse {
+    // Зсув і назва часового поясу локації (timezone=auto)
+    #[serde(default)]
+    pub utc_offset_seconds: i32,
+    #[serde(default)]
+    pub timezone: String,
     pub current: CurrentWeather,
 }
 
@@ -33,9 +173,23 @@ pub struct CurrentWeather {
     pub weather_code: i32,
     pub wind_speed_10m: f64,
     pub surface_pressure: f64,
+    // Місцевий час вимірювання, "2024-01-15T07:30"
+    #[serde(default)]
+    pub time: Option<String>,
+    // Видимість у метрах, хмарність у %, точка роси в °C, is_day: 1 — день, 0 — ніч
+    #[serde(default)]
+    pub visibility: Option<f64>,
+    #[serde(default)]
+    pub cloud_cover: Option<i32>,
+    #[serde(default)]
+    pub uv_index: Option<f64>,
+    #[serde(default)]
+    pub dew_point_2m: Option<f64>,
+    #[serde(default)]
+    pub is_day: Option<i32>,
 }
 
-// Структура для 7-денного прогнозу
+// Структура для прогнозу на 7–16 днів (див. ForecastRange)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForecastResponse {
     pub daily: DailyForecast,
@@ -50,166 +204,408 @@ pub struct DailyForecast {
     pub weather_code: Vec<i32>,
     pub sunrise: Vec<String>,
     pub sunset: Vec<String>,
+    #[serde(default)]
+    pub precipitation_probability_max: Vec<Option<i32>>,
+    #[serde(default)]
+    pub precipitation_sum: Vec<Option<f64>>,
+    #[serde(default)]
+    pub rain_sum: Vec<Option<f64>>,
+    #[serde(default)]
+    pub showers_sum: Vec<Option<f64>>,
+    #[serde(default)]
+    pub snowfall_sum: Vec<Option<f64>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HourlyForecast {
     pub time: Vec<String>,
-    pub temperature_2m: Vec<f64>,
-    pub apparent_temperature: Vec<f64>,
-    pub relative_humidity_2m: Vec<i32>,
-    pub surface_pressure: Vec<f64>,
-    pub wind_speed_10m: Vec<f64>,
-    pub wind_direction_10m: Vec<i32>,
+    // На далеких днях моделі дають дані з кроком 3–6 год, решта годин — null
+    pub temperature_2m: Vec<Option<f64>>,
+    pub apparent_temperature: Vec<Option<f64>>,
+    pub relative_humidity_2m: Vec<Option<i32>>,
+    pub surface_pressure: Vec<Option<f64>>,
+    pub wind_speed_10m: Vec<Option<f64>>,
+    pub wind_direction_10m: Vec<Option<i32>>,
+    // Пориви можуть бути відсутні для окремих годин або моделей
+    #[serde(default)]
+    pub wind_gusts_10m: Vec<Option<f64>>,
+    // Опади: ймовірність у %, кількість у мм, сніг у см
+    #[serde(default)]
+    pub precipitation_probability: Vec<Option<i32>>,
+    #[serde(default)]
+    pub precipitation: Vec<Option<f64>>,
+    #[serde(default)]
+    pub rain: Vec<Option<f64>>,
+    #[serde(default)]
+    pub showers: Vec<Option<f64>>,
+    #[serde(default)]
+    pub snowfall: Vec<Option<f64>>,
+    #[serde(default)]
+    pub visibility: Vec<Option<f64>>,
+    #[serde(default)]
+    pub cloud_cover: Vec<Option<i32>>,
+    #[serde(default)]
+    pub uv_index: Vec<Option<f64>>,
+    #[serde(default)]
+    pub dew_point_2m: Vec<Option<f64>>,
+    #[serde(default)]
+    pub is_day: Vec<Option<i32>>,
+    #[serde(default)]
+    pub weather_code: Vec<Option<i32>>,
+}
+
+impl HourlyForecast {
+    // Година має всі значення, які показує таблиця
+    fn is_complete(&self, idx: usize) -> bool {
+        fn present<T>(values: &[Option<T>], idx: usize) -> bool {
+            matches!(values.get(idx), Some(Some(_)))
+        }
+
+        present(&self.temperature_2m, idx)
+            && present(&self.apparent_temperature, idx)
+            && present(&self.relative_humidity_2m, idx)
+            && present(&self.surface_pressure, idx)
+            && present(&self.wind_speed_10m, idx)
+            && present(&self.wind_direction_10m, idx)
+    }
 }
 
 // Об'єднана структура для компонента
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherData {
     pub name: String,
     pub country: String,
+    pub admin1: Option<String>,
+    pub latitude: f64,
+    pub longitude: f64,
     pub main: MainWeather,
     pub weather: WeatherInfo,
     pub wind: Wind,
     pub sys: Sys,
-    pub visibility: i32,
+    // Метри; None, якщо модель не дає видимості
+    pub visibility: Option<f64>,
+    // Хмарність, %
+    pub clouds: Option<i32>,
+    pub uv_index: Option<f64>,
+    pub is_day: bool,
+    // Часовий пояс локації: дати й години прогнозу вказані в ньому
+    #[serde(default)]
+    pub utc_offset_seconds: i32,
+    #[serde(default)]
+    pub timezone: String,
     pub forecast: Option<Vec<DayForecast>>,
+    // Заповнюється, якщо поточна погода є, а прогноз отримати не вдалося
+    pub forecast_error: Option<WeatherError>,
 }
 
-// Прогноз на один день
-#[derive(Debug, Clone)]
+impl WeatherData {
+    // Місцевий час локації для моменту unix_seconds (UTC)
+    pub fn local_time(&self, unix_seconds: i64) -> LocalTime {
+        LocalTime::from_unix(unix_seconds, self.utc_offset_seconds)
+    }
+
+    pub fn region(&self) -> String {
+        join_region(self.admin1.as_deref(), &self.country)
+    }
+
+    // Чи світло в локації в момент unix_seconds — за сходом і заходом цього дня
+    // прогнозу; без них лишається is_day на момент запиту
+    pub fn is_day_at(&self, unix_seconds: i64) -> bool {
+        let local = self.local_time(unix_seconds);
+        let date = local.date();
+        self.forecast
+            .iter()
+            .flatten()
+            .find(|day| day.date == date)
+            .filter(|day| day.sunrise.is_some() && day.sunset.is_some())
+            .map_or(self.is_day, |day| {
+                is_daytime(&local.clock(), day.sunrise.as_deref(), day.sunset.as_deref())
+            })
+    }
+
+    // Локація, для якої отримано дані — щоб повторити запит без геокодингу
+    pub fn location(&self) -> GeoLocation {
+        GeoLocation {
+            name: self.name.clone(),
+            latitude: self.latitude,
+            longitude: self.longitude,
+            country: self.country.clone(),
+            admin1: self.admin1.clone(),
+            population: None,
+        }
+    }
+}
+
+// Прогноз на один день. Температури в °C, тиск у гПа — див. api::units
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DayForecast {
     pub date: String,
     pub day_name: String,
+    pub description: String,
     pub icon: String,
     pub icon_color: String,
-    pub temp_min: i32,
-    pub temp_max: i32,
-    pub hourly_temps: Vec<i32>,
-    pub hourly_feels: Vec<i32>,
-    pub hourly_pressure: Vec<i32>,
+    pub temp_min: f64,
+    pub temp_max: f64,
+    // Усі години дня: мітки "HH:MM" з HourlyForecast.time і значення з тими самими індексами
+    pub hourly_times: Vec<String>,
+    pub hourly_temps: Vec<f64>,
+    pub hourly_feels: Vec<f64>,
+    pub hourly_pressure: Vec<f64>,
     pub hourly_humidity: Vec<i32>,
-    pub hourly_wind: Vec<String>,
+    pub hourly_wind: Vec<HourlyWind>,
+    pub hourly_precipitation: Vec<Precipitation>,
+    pub hourly_conditions: Vec<HourlyConditions>,
+    // Опади за добу
+    pub precipitation: Precipitation,
     pub sunrise: Option<String>,
     pub sunset: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+impl DayForecast {
+    // Крок наявних погодинних даних: 1 год для найближчих днів, більше — для далеких
+    pub fn step_hours(&self) -> u32 {
+        let hours: Vec<u32> = self.hourly_times.iter().filter_map(|t| hour_of(t)).collect();
+        hours
+            .windows(2)
+            .filter_map(|pair| pair[1].checked_sub(pair[0]))
+            .filter(|&gap| gap > 0)
+            .min()
+            .unwrap_or(1)
+    }
+
+    // Індекси годин, які показуємо з обраною роздільністю
+    pub fn sample(&self, resolution: HourlyResolution) -> Vec<usize> {
+        let step = resolution.step();
+        self.hourly_times
+            .iter()
+            .enumerate()
+            .filter(|(_, time)| hour_of(time).is_some_and(|h| h % step == 0))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
+
+// Тривалість прогнозу. Open-Meteo віддає до 16 днів
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ForecastRange {
+    #[default]
+    Week,
+    TenDays,
+    SixteenDays,
+}
+
+impl ForecastRange {
+    pub const ALL: [ForecastRange; 3] = [
+        ForecastRange::Week,
+        ForecastRange::TenDays,
+        ForecastRange::SixteenDays,
+    ];
+
+    pub fn days(self) -> u32 {
+        match self {
+            ForecastRange::Week => 7,
+            ForecastRange::TenDays => 10,
+            ForecastRange::SixteenDays => 16,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ForecastRange::Week => "Тиждень",
+            ForecastRange::TenDays => "10 днів",
+            ForecastRange::SixteenDays => "16 днів",
+        }
+    }
+}
+
+// Крок погодинної таблиці
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HourlyResolution {
+    Hourly,
+    #[default]
+    ThreeHourly,
+    SixHourly,
+}
+
+impl HourlyResolution {
+    pub const ALL: [HourlyResolution; 3] = [
+        HourlyResolution::Hourly,
+        HourlyResolution::ThreeHourly,
+        HourlyResolution::SixHourly,
+    ];
+
+    pub fn step(self) -> u32 {
+        match self {
+            HourlyResolution::Hourly => 1,
+            HourlyResolution::ThreeHourly => 3,
+            HourlyResolution::SixHourly => 6,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HourlyResolution::Hourly => "Щогодини",
+            HourlyResolution::ThreeHourly => "Кожні 3 год",
+            HourlyResolution::SixHourly => "Кожні 6 год",
+        }
+    }
+}
+
+// Година з мітки "HH:MM" або з ISO 8601 "2024-01-15T07:30"
+pub fn hour_of(time: &str) -> Option<u32> {
+    let time = time.split('T').next_back()?;
+    time.split(':').next()?.parse().ok()
+}
+
+// Опади за годину або за добу. Ймовірність у %, дощ і зливи в мм, сніг у см
+// (як у відповіді Open-Meteo); відсутні значення вважаємо нулем
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Precipitation {
+    pub probability: Option<i32>,
+    pub total: f64,
+    pub rain: f64,
+    pub showers: f64,
+    pub snowfall: f64,
+}
+
+impl Precipitation {
+    // "дощ 0.4 мм, зливи 1.2 мм, сніг 0.7 см" — лише ненульові складові
+    pub fn breakdown(&self) -> String {
+        let parts: Vec<String> = [
+            ("дощ", self.rain, "мм"),
+            ("зливи", self.showers, "мм"),
+            ("сніг", self.snowfall, "см"),
+        ]
+        .iter()
+        .filter(|(_, amount, _)| *amount > 0.0)
+        .map(|(name, amount, unit)| format!("{} {:.1} {}", name, amount, unit))
+        .collect();
+
+        if parts.is_empty() {
+            "без опадів".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+// Видимість (м), хмарність (%), УФ-індекс, точка роси (°C) і світла пора доби за годину
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HourlyConditions {
+    pub weather_code: Option<i32>,
+    pub visibility: Option<f64>,
+    pub cloud_cover: Option<i32>,
+    pub uv_index: Option<f64>,
+    pub dew_point: Option<f64>,
+    pub is_day: bool,
+}
+
+// Вітер за годину: швидкість і пориви в км/год, напрямок у градусах (звідки дме)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HourlyWind {
+    pub speed: f64,
+    pub gusts: Option<f64>,
+    pub direction: i32,
+}
+
+impl HourlyWind {
+    pub fn arrow(&self) -> &'static str {
+        get_wind_direction(self.direction)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MainWeather {
     pub temp: f64,
     pub feels_like: f64,
     pub humidity: i32,
-    pub pressure: i32,
+    // гПа
+    pub pressure: f64,
+    // Точка роси, °C
+    pub dew_point: Option<f64>,
 }
 
-#[derive(Debug, Clone)]
+// Поточні умови без прив'язки до пори доби: іконку й опис обирають під час
+// відображення, тож збережені дані вночі показують місяць, а не сонце
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherInfo {
-    pub description: String,
-    pub icon: String,
+    // Код погоди WMO
+    pub code: i32,
+    // Коди іконок на кшталт "01d" (день) і "01n" (ніч)
+    pub icon_day: String,
+    pub icon_night: String,
 }
 
-#[derive(Debug, Clone)]
+impl WeatherInfo {
+    pub fn from_code(code: i32) -> Self {
+        let weather = WeatherCode::from_code(code);
+        Self {
+            code,
+            icon_day: weather.icon_code(true),
+            icon_night: weather.icon_code(false),
+        }
+    }
+
+    pub fn description(&self, is_day: bool) -> &'static str {
+        WeatherCode::from_code(self.code).description(is_day)
+    }
+
+    // Lucide-іконка і колір
+    pub fn icon(&self, is_day: bool) -> (&'static str, &'static str) {
+        get_weather_icon(self.code, is_day)
+    }
+
+    pub fn icon_code(&self, is_day: bool) -> &str {
+        if is_day {
+            &self.icon_day
+        } else {
+            &self.icon_night
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Wind {
+    // км/год
     pub speed: f64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sys {
     pub country: String,
 }
 
-// Функція для отримання Lucide іконки за кодом WMO
-fn get_weather_icon(code: i32) -> (&'static str, &'static str) {
-    match code {
-        0 => ("sun", "text-yellow-400"),
-        1 | 2 => ("cloud-sun", "text-yellow-200"),
-        3 => ("cloud", "text-gray-400"),
-        45 | 48 => ("cloud-fog", "text-gray-300"),
-        51 | 53 | 55 => ("cloud-drizzle", "text-blue-300"),
-        56 | 57 => ("snowflake", "text-blue-200"),
-        61 | 63 | 65 => ("cloud-rain", "text-blue-400"),
-        66 | 67 => ("cloud-snow", "text-white"),
-        71 | 73 | 75 => ("cloud-snow", "text-white"),
-        77 => ("cloud-hail", "text-blue-100"),
-        80 | 81 | 82 => ("cloud-rain-wind", "text-blue-500"),
-        85 | 86 => ("snowflake", "text-white"),
-        95 => ("cloud-lightning", "text-purple-400"),
-        96 | 99 => ("cloud-lightning", "text-purple-500"),
-        _ => ("cloud", "text-gray-400"),
-    }
-}
-
-// Функція для отримання опису погоди за кодом WMO
-fn get_weather_description(code: i32) -> (&'static str, &'static str) {
-    match code {
-        0 => ("Ясно", "01d"),
-        1 | 2 => ("Переважно ясно", "02d"),
-        3 => ("Хмарно", "03d"),
-        45 | 48 => ("Туман", "50d"),
-        51 | 53 | 55 => ("Мряка", "09d"),
-        56 | 57 => ("Ожеледь", "13d"),
-        61 | 63 | 65 => ("Дощ", "10d"),
-        66 | 67 => ("Мокрий сніг", "13d"),
-        71 | 73 | 75 => ("Сніг", "13d"),
-        77 => ("Град", "13d"),
-        80 | 81 | 82 => ("Зливи", "09d"),
-        85 | 86 => ("Снігопад", "13d"),
-        95 => ("Гроза", "11d"),
-        96 | 99 => ("Гроза з градом", "11d"),
-        _ => ("Невідомо", "01d"),
-    }
-}
-
-async fn get_coordinates(city: &str) -> Result<GeoLocation, String> {
-    let url = format!(
-        "{}?name={}&count=1&language=uk&format=json",
-        GEOCODING_API, 
-        urlencoding::encode(city)
-    );
-
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Помилка мережі: {}", e))?;
-
-    if !response.ok() {
-        return Err(format!("Помилка geocoding: {}", response.status()));
-    }
-
-    let geo_response: GeocodingResponse = response
-        .json()
-        .await
-        .map_err(|e| format!("Помилка парсингу: {}", e))?;
-
-    geo_response
-        .results
-        .and_then(|mut r| r.pop())
-        .ok_or_else(|| "Місто не знайдено".to_string())
+// Функція для отримання Lucide іконки за кодом WMO (див. api::wmo)
+pub fn get_weather_icon(code: i32, is_day: bool) -> (&'static str, &'static str) {
+    WeatherCode::from_code(code).icon(is_day)
 }
 
-async fn get_weather_by_coords(lat: f64, lon: f64) -> Result<WeatherResponse, String> {
-    let url = format!(
-        "{}?latitude={}&longitude={}&current=temperature_2m,relative_humidity_2m,apparent_temperature,weather_code,wind_speed_10m,surface_pressure",
-        WEATHER_API, lat, lon
-    );
-
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Помилка мережі: {}", e))?;
+// Функція для отримання опису погоди і коду іконки ("01d" / "01n") за кодом WMO
+pub fn get_weather_description(code: i32, is_day: bool) -> (&'static str, String) {
+    let weather = WeatherCode::from_code(code);
+    (weather.description(is_day), weather.icon_code(is_day))
+}
 
-    if !response.ok() {
-        return Err(format!("Помилка погоди: {}", response.status()));
+// Чи світло о time ("HH:MM") між sunrise і sunset того ж дня. Запасний варіант,
+// коли Open-Meteo не повернув is_day; без сходу/заходу вважаємо, що день
+fn is_daytime(time: &str, sunrise: Option<&str>, sunset: Option<&str>) -> bool {
+    match (sunrise, sunset) {
+        (Some(sunrise), Some(sunset)) => sunrise <= time && time < sunset,
+        _ => true,
     }
+}
 
-    response
-        .json::<WeatherResponse>()
-        .await
-        .map_err(|e| format!("Помилка парсингу даних: {}", e))
+fn decode_json<T: DeserializeOwned>(body: &str, endpoint: Endpoint) -> Result<T, WeatherError> {
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(deserializer).map_err(|e| WeatherError::Decode {
+        endpoint,
+        path: e.path().to_string(),
+        message: e.inner().to_string(),
+    })
 }
 
 // Функція для отримання напрямку вітру
-fn get_wind_direction(degrees: i32) -> &'static str {
+pub fn get_wind_direction(degrees: i32) -> &'static str {
     match degrees {
         0..=22 | 338..=360 => "↑",
         23..=67 => "↗",
@@ -223,8 +619,52 @@ fn get_wind_direction(degrees: i32) -> &'static str {
     }
 }
 
+// Словесні оцінки для панелі поточних умов
+
+// УФ-індекс за шкалою ВООЗ
+pub fn uv_band(uv_index: f64) -> &'static str {
+    match uv_index {
+        x if x < 3.0 => "низький",
+        x if x < 6.0 => "помірний",
+        x if x < 8.0 => "високий",
+        x if x < 11.0 => "дуже високий",
+        _ => "екстремальний",
+    }
+}
+
+// Видимість у метрах
+pub fn visibility_band(meters: f64) -> &'static str {
+    match meters {
+        x if x < 1_000.0 => "дуже погана",
+        x if x < 4_000.0 => "погана",
+        x if x < 10_000.0 => "помірна",
+        x if x < 20_000.0 => "добра",
+        _ => "відмінна",
+    }
+}
+
+// Хмарність у відсотках
+pub fn cloud_band(percent: i32) -> &'static str {
+    match percent {
+        i32::MIN..=10 => "ясно",
+        11..=50 => "мінлива хмарність",
+        51..=85 => "хмарно",
+        _ => "суцільна хмарність",
+    }
+}
+
+// Наскільки волого відчувається повітря за точкою роси в °C
+pub fn dew_point_band(celsius: f64) -> &'static str {
+    match celsius {
+        x if x < 10.0 => "сухо",
+        x if x < 16.0 => "комфортно",
+        x if x < 20.0 => "волого",
+        _ => "задушливо",
+    }
+}
+
 // Функція для форматування часу з ISO 8601 в HH:MM
-fn format_time(datetime: &str) -> String {
+pub fn format_time(datetime: &str) -> String {
     // Формат: "2026-02-28T06:37" -> "06:37"
     if let Some(time_part) = datetime.split('T').nth(1) {
         time_part.split(':').take(2).collect::<Vec<_>>().join(":")
@@ -234,7 +674,7 @@ fn format_time(datetime: &str) -> String {
 }
 
 // Функція для отримання назви дня тижня українською
-fn get_day_name(date_str: &str) -> String {
+pub fn get_day_name(date_str: &str) -> String {
     // Парсимо дату формату "2026-02-28"
     let parts: Vec<&str> = date_str.split('-').collect();
     if parts.len() != 3 {
@@ -258,117 +698,759 @@ fn get_day_name(date_str: &str) -> String {
     format!("{} {}/{}", day_name, day, month)
 }
 
-async fn get_forecast_by_coords(lat: f64, lon: f64) -> Result<ForecastResponse, String> {
-    let url = format!(
-        "{}?latitude={}&longitude={}&daily=temperature_2m_max,temperature_2m_min,weather_code,sunrise,sunset&hourly=temperature_2m,apparent_temperature,relative_humidity_2m,surface_pressure,wind_speed_10m,wind_direction_10m&forecast_days=7&timezone=auto",
-        WEATHER_API, lat, lon
-    );
+impl<C: HttpClient> WeatherClient<C> {
+    pub fn new(http: C, config: ApiConfig) -> Self {
+        Self { http, config }
+    }
 
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Помилка мережі: {}", e))?;
+    pub fn config(&self) -> &ApiConfig {
+        &self.config
+    }
 
-    if !response.ok() {
-        return Err(format!("Помилка прогнозу: {}", response.status()));
+    // Виконує GET-запит і розбирає JSON, зберігаючи шлях до поля у разі помилки
+    async fn fetch_json<T: DeserializeOwned>(&self, url: &str, endpoint: Endpoint) -> Result<T, WeatherError> {
+        let response = self
+            .http
+            .get_from(url, endpoint)
+            .await
+            .map_err(|message| WeatherError::Network { endpoint, message })?;
+
+        if !response.ok() {
+            return Err(WeatherError::Http {
+                endpoint,
+                status: response.status,
+            });
+        }
+
+        decode_json(&response.body, endpoint)
     }
 
-    response
-        .json::<ForecastResponse>()
-        .await
-        .map_err(|e| format!("Помилка парсингу прогнозу: {}", e))
+    // Усі кандидати в порядку, в якому їх ранжує Open-Meteo
+    pub async fn search_locations(&self, query: &str, count: usize) -> Result<Vec<GeoLocation>, WeatherError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(WeatherError::EmptyQuery);
+        }
+
+        let url = format!(
+            "{}?name={}&count={}&language=uk&format=json",
+            self.config.geocoding_url,
+            urlencoding::encode(query),
+            count
+        );
+
+        let geo_response: GeocodingResponse = self.fetch_json(&url, Endpoint::Geocoding).await?;
+
+        match geo_response.results {
+            Some(results) if !results.is_empty() => Ok(results),
+            _ => Err(WeatherError::CityNotFound {
+                query: query.to_string(),
+            }),
+        }
+    }
+
+    // Перший кандидат — коли вибір користувача неможливий (CLI, сервіси)
+    pub async fn get_coordinates(&self, city: &str) -> Result<GeoLocation, WeatherError> {
+        let mut results = self.search_locations(city, 1).await?;
+        Ok(results.swap_remove(0))
+    }
+
+    // Шаблон тайлів останнього кадру радара опадів, з {z}/{x}/{y} як у map_tiles_url
+    pub async fn radar_tiles(&self) -> Result<String, WeatherError> {
+        let response: RadarMapsResponse = self.fetch_json(&self.config.radar_url, Endpoint::Radar).await?;
+        let frame = response.radar.past.last().ok_or_else(|| WeatherError::Decode {
+            endpoint: Endpoint::Radar,
+            path: "radar.past".to_string(),
+            message: "немає жодного кадру".to_string(),
+        })?;
+
+        // 256 — розмір тайла, 2 — кольорова схема, 1_1 — згладжування і сніг
+        Ok(format!("{}{}/256/{{z}}/{{x}}/{{y}}/2/1_1.png", response.host, frame.path))
+    }
+
+    // Назва населеного пункту за координатами
+    pub async fn reverse_geocode(&self, lat: f64, lon: f64) -> Result<GeoLocation, WeatherError> {
+        let url = format!(
+            "{}?latitude={}&longitude={}&localityLanguage=uk",
+            self.config.reverse_geocoding_url, lat, lon
+        );
+
+        let response: ReverseGeocodingResponse = self.fetch_json(&url, Endpoint::ReverseGeocoding).await?;
+
+        let name = if response.city.is_empty() {
+            response.locality
+        } else {
+            response.city
+        };
+        if name.is_empty() {
+            return Err(WeatherError::CityNotFound {
+                query: GeoLocation::from_coords(lat, lon).name,
+            });
+        }
+
+        Ok(GeoLocation {
+            name,
+            latitude: lat,
+            longitude: lon,
+            country: response.country_name,
+            admin1: Some(response.principal_subdivision).filter(|s| !s.is_empty()),
+            population: None,
+        })
+    }
+
+    // Як reverse_geocode, але ніколи не падає: без назви повертає підпис "lat, lon"
+    pub async fn locate(&self, lat: f64, lon: f64) -> GeoLocation {
+        self.reverse_geocode(lat, lon)
+            .await
+            .unwrap_or_else(|_| GeoLocation::from_coords(lat, lon))
+    }
+
+    pub async fn get_weather_by_coords(&self, lat: f64, lon: f64) -> Result<WeatherResponse, WeatherError> {
+        let url = format!(
+            "{}?latitude={}&longitude={}&current=temperature_2m,relative_humidity_2m,apparent_temperature,weather_code,wind_speed_10m,surface_pressure,visibility,cloud_cover,uv_index,dew_point_2m,is_day&timezone=auto",
+            self.config.weather_url, lat, lon
+        );
+
+        self.fetch_json(&url, Endpoint::Current).await
+    }
+
+    pub async fn get_forecast_by_coords(&self, lat: f64, lon: f64, range: ForecastRange) -> Result<ForecastResponse, WeatherError> {
+        let url = format!(
+            "{}?latitude={}&longitude={}&daily=temperature_2m_max,temperature_2m_min,weather_code,sunrise,sunset,precipitation_probability_max,precipitation_sum,rain_sum,showers_sum,snowfall_sum&hourly=temperature_2m,apparent_temperature,relative_humidity_2m,surface_pressure,wind_speed_10m,wind_direction_10m,wind_gusts_10m,precipitation_probability,precipitation,rain,showers,snowfall,visibility,cloud_cover,uv_index,dew_point_2m,is_day,weather_code&forecast_days={}&timezone=auto",
+            self.config.weather_url, lat, lon, range.days()
+        );
+
+        self.fetch_json(&url, Endpoint::Forecast).await
+    }
+
+    pub async fn get_weather(&self, city: &str) -> Result<WeatherData, WeatherError> {
+        // Отримуємо координати міста
+        let location = self.get_coordinates(city).await?;
+        self.get_weather_for(location).await
+    }
+
+    // Погода і тижневий прогноз для вже відомої локації, без геокодингу
+    pub async fn get_weather_for(&self, location: GeoLocation) -> Result<WeatherData, WeatherError> {
+        self.get_weather_for_range(location, ForecastRange::default()).await
+    }
+
+    pub async fn get_weather_for_range(&self, location: GeoLocation, range: ForecastRange) -> Result<WeatherData, WeatherError> {
+        // Отримуємо поточну погоду
+        let weather = self.get_weather_by_coords(location.latitude, location.longitude).await?;
+        
+        // Отримуємо прогноз на обрану кількість днів
+        let forecast = self.get_forecast_by_coords(location.latitude, location.longitude, range).await;
+
+        Ok(build_weather_data(location, weather, forecast))
+    }
 }
 
-pub async fn get_weather(city: &str) -> Result<WeatherData, String> {
-    // Отримуємо координати міста
-    let location = get_coordinates(city).await?;
-    
-    // Отримуємо поточну погоду
-    let weather = get_weather_by_coords(location.latitude, location.longitude).await?;
-    
-    // Отримуємо 7-денний прогноз
-    let forecast_result = get_forecast_by_coords(location.latitude, location.longitude).await;
+// Нарізає погодинні дані по днях так само, як їх показує веб-застосунок
+pub fn build_day_forecasts(forecast: &ForecastResponse) -> Vec<DayForecast> {
+    let mut days = Vec::new();
     
-    let forecast_days = if let Ok(forecast) = forecast_result {
-        let mut days = Vec::new();
+    for (i, date) in forecast.daily.time.iter().enumerate() {
+        // Масиви daily можуть бути коротшими за time (стаб, неповна відповідь моделі):
+        // день без коду погоди чи температур пропускаємо
+        let (Some(&code), Some(&temp_min), Some(&temp_max)) = (
+            forecast.daily.weather_code.get(i),
+            forecast.daily.temperature_2m_min.get(i),
+            forecast.daily.temperature_2m_max.get(i),
+        ) else {
+            continue;
+        };
+        let day_name = get_day_name(date);
+        // Іконка й опис дня — завжди денний варіант
+        let weather = WeatherCode::from_code(code);
+        let (icon, icon_color) = weather.icon(true);
         
-        for i in 0..7.min(forecast.daily.time.len()) {
-            let date = &forecast.daily.time[i];
-            let day_name = get_day_name(date);
-            let (icon, icon_color) = get_weather_icon(forecast.daily.weather_code[i]);
-            
-            // Отримуємо погодинні дані для цього дня (кожні 3 години: 0, 3, 9, 12, 15, 18, 21)
-            let hours = vec![0, 3, 9, 12, 15, 18, 21];
-            let base_hour = i * 24;
-            
-            let hourly_temps: Vec<i32> = hours.iter()
-                .filter_map(|h| forecast.hourly.temperature_2m.get(base_hour + h).map(|&t| t as i32))
-                .collect();
-            
-            let hourly_feels: Vec<i32> = hours.iter()
-                .filter_map(|h| forecast.hourly.apparent_temperature.get(base_hour + h).map(|&t| t as i32))
-                .collect();
-            
-            let hourly_pressure: Vec<i32> = hours.iter()
-                .filter_map(|h| forecast.hourly.surface_pressure.get(base_hour + h).map(|&p| (p * 0.75).round() as i32))
-                .collect();
-            
-            let hourly_humidity: Vec<i32> = hours.iter()
-                .filter_map(|h| forecast.hourly.relative_humidity_2m.get(base_hour + h).copied())
-                .collect();
-            
-            let hourly_wind: Vec<String> = hours.iter()
-                .filter_map(|h| forecast.hourly.wind_direction_10m.get(base_hour + h).map(|&d| get_wind_direction(d).to_string()))
-                .collect();
-            
-            // Форматуємо час схід/заходу (ISO 8601 -> HH:MM)
-            let sunrise = forecast.daily.sunrise.get(i).map(|s| format_time(s));
-            let sunset = forecast.daily.sunset.get(i).map(|s| format_time(s));
-            
-            days.push(DayForecast {
-                date: date.clone(),
-                day_name,
-                icon: icon.to_string(),
-                icon_color: icon_color.to_string(),
-                temp_min: forecast.daily.temperature_2m_min[i] as i32,
-                temp_max: forecast.daily.temperature_2m_max[i] as i32,
-                hourly_temps,
-                hourly_feels,
-                hourly_pressure,
-                hourly_humidity,
-                hourly_wind,
-                sunrise,
-                sunset,
-            });
-        }
+        // Години цього дня — ті, чия мітка часу має ту саму дату. Доба переходу
+        // на літній/зимовий час має 23 або 25 записів, а вікно API не обов'язково
+        // починається з опівночі, тож індекси на кшталт i * 24 + h не годяться
+        let hours: Vec<usize> = hours_of_day(&forecast.hourly.time, date)
+            .into_iter()
+            .filter(|&idx| forecast.hourly.is_complete(idx))
+            .collect();
         
-        Some(days)
-    } else {
-        None
+        let hourly_times: Vec<String> = hours.iter()
+            .map(|&idx| format_time(&forecast.hourly.time[idx]))
+            .collect();
+        
+        let hourly_temps: Vec<f64> = hours.iter()
+            .filter_map(|&idx| forecast.hourly.temperature_2m.get(idx).copied().flatten())
+            .collect();
+        
+        let hourly_feels: Vec<f64> = hours.iter()
+            .filter_map(|&idx| forecast.hourly.apparent_temperature.get(idx).copied().flatten())
+            .collect();
+        
+        let hourly_pressure: Vec<f64> = hours.iter()
+            .filter_map(|&idx| forecast.hourly.surface_pressure.get(idx).copied().flatten())
+            .collect();
+        
+        let hourly_humidity: Vec<i32> = hours.iter()
+            .filter_map(|&idx| forecast.hourly.relative_humidity_2m.get(idx).copied().flatten())
+            .collect();
+        
+        let hourly_wind: Vec<HourlyWind> = hours.iter()
+            .filter_map(|&idx| {
+                Some(HourlyWind {
+                    speed: forecast.hourly.wind_speed_10m.get(idx).copied().flatten()?,
+                    gusts: forecast.hourly.wind_gusts_10m.get(idx).copied().flatten(),
+                    direction: forecast.hourly.wind_direction_10m.get(idx).copied().flatten()?,
+                })
+            })
+            .collect();
+        
+        let hourly_precipitation: Vec<Precipitation> = hours.iter()
+            .map(|&idx| {
+                let hourly = &forecast.hourly;
+                Precipitation {
+                    probability: value_at(&hourly.precipitation_probability, idx),
+                    total: value_at(&hourly.precipitation, idx).unwrap_or_default(),
+                    rain: value_at(&hourly.rain, idx).unwrap_or_default(),
+                    showers: value_at(&hourly.showers, idx).unwrap_or_default(),
+                    snowfall: value_at(&hourly.snowfall, idx).unwrap_or_default(),
+                }
+            })
+            .collect();
+        
+        // Форматуємо час схід/заходу (ISO 8601 -> HH:MM)
+        let sunrise = forecast.daily.sunrise.get(i).map(|s| format_time(s));
+        let sunset = forecast.daily.sunset.get(i).map(|s| format_time(s));
+        
+        let hourly_conditions: Vec<HourlyConditions> = hours.iter()
+            .map(|&idx| {
+                let hourly = &forecast.hourly;
+                let is_day = match value_at(&hourly.is_day, idx) {
+                    Some(flag) => flag != 0,
+                    None => is_daytime(&format_time(&hourly.time[idx]), sunrise.as_deref(), sunset.as_deref()),
+                };
+                HourlyConditions {
+                    weather_code: value_at(&hourly.weather_code, idx),
+                    visibility: value_at(&hourly.visibility, idx),
+                    cloud_cover: value_at(&hourly.cloud_cover, idx),
+                    uv_index: value_at(&hourly.uv_index, idx),
+                    dew_point: value_at(&hourly.dew_point_2m, idx),
+                    is_day,
+                }
+            })
+            .collect();
+        
+        let daily = &forecast.daily;
+        let precipitation = Precipitation {
+            probability: value_at(&daily.precipitation_probability_max, i),
+            total: value_at(&daily.precipitation_sum, i).unwrap_or_default(),
+            rain: value_at(&daily.rain_sum, i).unwrap_or_default(),
+            showers: value_at(&daily.showers_sum, i).unwrap_or_default(),
+            snowfall: value_at(&daily.snowfall_sum, i).unwrap_or_default(),
+        };
+        
+        days.push(DayForecast {
+            date: date.clone(),
+            day_name,
+            description: weather.description(true).to_string(),
+            icon: icon.to_string(),
+            icon_color: icon_color.to_string(),
+            temp_min,
+            temp_max,
+            hourly_times,
+            hourly_temps,
+            hourly_feels,
+            hourly_pressure,
+            hourly_humidity,
+            hourly_wind,
+            hourly_precipitation,
+            hourly_conditions,
+            precipitation,
+            sunrise,
+            sunset,
+        });
+    }
+
+    days
+}
+
+// Значення з масиву Open-Meteo, де окремі елементи можуть бути null
+fn value_at<T: Copy>(values: &[Option<T>], idx: usize) -> Option<T> {
+    values.get(idx).copied().flatten()
+}
+
+// Індекси погодинних записів, що належать дню date ("2024-01-15").
+// Open-Meteo з timezone=auto повертає місцевий час: "2024-01-15T07:00"
+fn hours_of_day(times: &[String], date: &str) -> Vec<usize> {
+    times
+        .iter()
+        .enumerate()
+        .filter(|(_, time)| time.split_once('T').is_some_and(|(day, _)| day == date))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+// Збирає WeatherData з уже отриманих відповідей API, без звернень до мережі.
+// Помилка прогнозу не фатальна: її зберігаємо у forecast_error
+pub fn build_weather_data(
+    location: GeoLocation,
+    weather: WeatherResponse,
+    forecast: Result<ForecastResponse, WeatherError>,
+) -> WeatherData {
+    let (forecast_days, forecast_error) = match forecast {
+        Ok(forecast) => (Some(build_day_forecasts(&forecast)), None),
+        Err(e) => (None, Some(WeatherError::PartialForecast(Box::new(e)))),
+    };
+
+    // Пора доби: is_day з відповіді, інакше — схід і захід сьогоднішнього дня прогнозу
+    let is_day = match (weather.current.is_day, &weather.current.time) {
+        (Some(flag), _) => flag != 0,
+        (None, Some(time)) => forecast_days
+            .iter()
+            .flatten()
+            .find(|day| time.starts_with(&day.date))
+            .is_none_or(|day| is_daytime(&format_time(time), day.sunrise.as_deref(), day.sunset.as_deref())),
+        (None, None) => true,
     };
     
-    let (description, icon) = get_weather_description(weather.current.weather_code);
-    
-    Ok(WeatherData {
+    WeatherData {
         name: location.name.clone(),
         country: location.country.clone(),
+        admin1: location.admin1.clone(),
+        latitude: location.latitude,
+        longitude: location.longitude,
         main: MainWeather {
             temp: weather.current.temperature_2m,
             feels_like: weather.current.apparent_temperature,
             humidity: weather.current.relative_humidity_2m,
-            pressure: weather.current.surface_pressure as i32,
-        },
-        weather: WeatherInfo {
-            description: description.to_string(),
-            icon: icon.to_string(),
+            pressure: weather.current.surface_pressure,
+            dew_point: weather.current.dew_point_2m,
         },
+        weather: WeatherInfo::from_code(weather.current.weather_code),
         wind: Wind {
-            speed: weather.current.wind_speed_10m / 3.6,
+            speed: weather.current.wind_speed_10m,
         },
         sys: Sys {
             country: location.country,
         },
-        visibility: 10,
+        visibility: weather.current.visibility,
+        clouds: weather.current.cloud_cover,
+        uv_index: weather.current.uv_index,
+        is_day,
+        utc_offset_seconds: weather.utc_offset_seconds,
+        timezone: weather.timezone,
         forecast: forecast_days,
-    })
+        forecast_error,
+    }
+}
+
+// Скорочення для UI: клієнт за замовчуванням з налаштуваннями з оточення
+pub async fn get_weather(city: &str) -> Result<WeatherData, WeatherError> {
+    WeatherClient::default().get_weather(city).await
+}
+
+pub async fn get_weather_for(location: GeoLocation) -> Result<WeatherData, WeatherError> {
+    WeatherClient::default().get_weather_for(location).await
+}
+
+pub async fn get_weather_for_range(location: GeoLocation, range: ForecastRange) -> Result<WeatherData, WeatherError> {
+    WeatherClient::default().get_weather_for_range(location, range).await
+}
+
+pub async fn search_locations(query: &str) -> Result<Vec<GeoLocation>, WeatherError> {
+    WeatherClient::default().search_locations(query, SEARCH_RESULTS).await
+}
+
+// Підказки під час набору: запит переривається через abort.abort() і не кешується
+pub async fn suggest_locations(query: &str, abort: &AbortHandle) -> Result<Vec<GeoLocation>, WeatherError> {
+    WeatherClient::new(abort.client(), ApiConfig::default())
+        .search_locations(query, SEARCH_RESULTS)
+        .await
+}
+
+pub async fn locate(lat: f64, lon: f64) -> GeoLocation {
+    WeatherClient::default().locate(lat, lon).await
+}
+
+// У браузері перелік кадрів береться з кешу DefaultClient (до 5 хв, див. CachePolicy)
+pub async fn radar_tiles() -> Result<String, WeatherError> {
+    WeatherClient::default().radar_tiles().await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll, Waker};
+
+    use super::*;
+    use crate::api::cache::{CachedClient, MemoryStore};
+    use crate::api::http::HttpResponse;
+
+    // Фікстури — відповіді Open-Meteo для Києва навколо переходу на літній (29.03.2026)
+    // і зимовий (25.10.2026) час. Температура дорівнює індексу погодинного запису
+    fn fixture(json: &str) -> ForecastResponse {
+        serde_json::from_str(json).expect("фікстура має відповідати ForecastResponse")
+    }
+
+    fn spring_forward() -> ForecastResponse {
+        fixture(include_str!("fixtures/dst_spring_forward.json"))
+    }
+
+    fn fall_back() -> ForecastResponse {
+        fixture(include_str!("fixtures/dst_fall_back.json"))
+    }
+
+    // Транспорт-заглушка: записані відповіді Open-Meteo за фрагментом адреси
+    #[derive(Clone, Default)]
+    struct StubHttp {
+        routes: Vec<(&'static str, u16, &'static str)>,
+        calls: Rc<Cell<u32>>,
+    }
+
+    impl StubHttp {
+        fn with(mut self, needle: &'static str, status: u16, body: &'static str) -> Self {
+            self.routes.push((needle, status, body));
+            self
+        }
+
+        // Київ: геокодинг, поточна погода і тридобовий прогноз
+        fn kyiv() -> Self {
+            Self::default()
+                .with("?name=", 200, include_str!("fixtures/geocoding_kyiv.json"))
+                .with("&current=", 200, include_str!("fixtures/current_kyiv.json"))
+                .with("&daily=", 200, include_str!("fixtures/dst_spring_forward.json"))
+        }
+    }
+
+    impl HttpClient for StubHttp {
+        async fn get(&self, url: &str) -> Result<HttpResponse, String> {
+            self.calls.set(self.calls.get() + 1);
+            self.routes
+                .iter()
+                .find(|(needle, ..)| url.contains(needle))
+                .map(|&(_, status, body)| HttpResponse {
+                    status,
+                    body: body.to_string(),
+                })
+                .ok_or_else(|| format!("немає відповіді для {}", url))
+        }
+    }
+
+    fn stub_client<C: HttpClient>(http: C) -> WeatherClient<C> {
+        WeatherClient::new(
+            http,
+            ApiConfig {
+                geocoding_url: "https://geocoding.test/v1/search".to_string(),
+                weather_url: "https://forecast.test/v1/forecast".to_string(),
+                reverse_geocoding_url: "https://reverse.test/reverse-geocode".to_string(),
+                map_tiles_url: "https://tiles.test/{z}/{x}/{y}.png".to_string(),
+                radar_url: "https://radar.test/weather-maps.json".to_string(),
+            },
+        )
+    }
+
+    // StubHttp відповідає одразу, тож future готовий з першого опитування
+    fn ready<T>(future: impl Future<Output = T>) -> T {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("future не завершився"),
+        }
+    }
+
+    #[test]
+    fn geocode_current_and_forecast_build_weather_data() {
+        let data = ready(stub_client(StubHttp::kyiv()).get_weather("Київ")).expect("погода для Києва");
+
+        assert_eq!(data.name, "Київ");
+        assert_eq!(data.country, "Україна");
+        assert_eq!(data.latitude, 50.45466);
+        assert_eq!(data.main.temp, 8.4);
+        assert_eq!(data.main.humidity, 61);
+        assert_eq!(data.wind.speed, 12.2);
+        assert_eq!(data.clouds, Some(100));
+        assert_eq!(data.timezone, "Europe/Kyiv");
+        assert!(data.is_day);
+        assert!(data.forecast_error.is_none());
+        let days = data.forecast.expect("прогноз");
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0].date, "2026-03-28");
+    }
+
+    #[test]
+    fn non_2xx_status_maps_to_http_error_with_endpoint() {
+        let client = stub_client(StubHttp::default().with("?name=", 500, "Internal Server Error"));
+        assert_eq!(
+            ready(client.get_weather("Київ")).unwrap_err(),
+            WeatherError::Http {
+                endpoint: Endpoint::Geocoding,
+                status: 500
+            }
+        );
+
+        let client = stub_client(
+            StubHttp::default()
+                .with("?name=", 200, include_str!("fixtures/geocoding_kyiv.json"))
+                .with("&current=", 404, "Not Found"),
+        );
+        assert_eq!(
+            ready(client.get_weather("Київ")).unwrap_err(),
+            WeatherError::Http {
+                endpoint: Endpoint::Current,
+                status: 404
+            }
+        );
+    }
+
+    // Прогноз не фатальний: поточна погода є, помилка — у forecast_error
+    #[test]
+    fn forecast_status_error_is_kept_as_partial() {
+        let http = StubHttp::default()
+            .with("?name=", 200, include_str!("fixtures/geocoding_kyiv.json"))
+            .with("&current=", 200, include_str!("fixtures/current_kyiv.json"))
+            .with("&daily=", 503, "Service Unavailable");
+
+        let data = ready(stub_client(http).get_weather("Київ")).expect("поточна погода");
+
+        assert!(data.forecast.is_none());
+        assert_eq!(
+            data.forecast_error,
+            Some(WeatherError::PartialForecast(Box::new(WeatherError::Http {
+                endpoint: Endpoint::Forecast,
+                status: 503
+            })))
+        );
+    }
+
+    #[test]
+    fn malformed_body_maps_to_decode_error() {
+        let http = StubHttp::default()
+            .with("?name=", 200, include_str!("fixtures/geocoding_kyiv.json"))
+            .with("&current=", 200, r#"{"current": {"temperature_2m": "тепло"}}"#);
+
+        match ready(stub_client(http).get_weather("Київ")) {
+            Err(WeatherError::Decode { endpoint, path, .. }) => {
+                assert_eq!(endpoint, Endpoint::Current);
+                assert_eq!(path, "current.temperature_2m");
+            }
+            other => panic!("очікували Decode, отримали {:?}", other.map(|data| data.name)),
+        }
+    }
+
+    #[test]
+    fn empty_geocoding_results_map_to_city_not_found() {
+        for body in [r#"{"results": []}"#, r#"{"generationtime_ms": 0.5}"#] {
+            let client = stub_client(StubHttp::default().with("?name=", 200, body));
+
+            assert_eq!(
+                ready(client.get_weather("  Атлантида ")).unwrap_err(),
+                WeatherError::CityNotFound {
+                    query: "Атлантида".to_string()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn spring_forward_day_has_23_hours() {
+        let days = build_day_forecasts(&spring_forward());
+
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0].hourly_times.len(), 24);
+        assert_eq!(days[1].hourly_times.len(), 23);
+        assert!(!days[1].hourly_times.contains(&"03:00".to_string()));
+        assert_eq!(days[1].hourly_times[2..4], ["02:00", "04:00"]);
+        assert_eq!(days[2].hourly_times.len(), 24);
+    }
+
+    #[test]
+    fn spring_forward_next_day_starts_at_its_midnight() {
+        let days = build_day_forecasts(&spring_forward());
+
+        assert_eq!(days[1].hourly_temps.first(), Some(&24.0));
+        assert_eq!(days[2].hourly_times.first().map(String::as_str), Some("00:00"));
+        assert_eq!(days[2].hourly_temps.first(), Some(&47.0));
+    }
+
+    #[test]
+    fn fall_back_day_has_25_hours() {
+        let days = build_day_forecasts(&fall_back());
+
+        assert_eq!(days[1].hourly_times.len(), 25);
+        assert_eq!(days[1].hourly_times.iter().filter(|t| *t == "03:00").count(), 2);
+        assert_eq!(days[1].hourly_temps.last(), Some(&48.0));
+        assert_eq!(days[2].hourly_times.first().map(String::as_str), Some("00:00"));
+        assert_eq!(days[2].hourly_temps.first(), Some(&49.0));
+    }
+
+    #[test]
+    fn hourly_columns_stay_aligned_on_dst_days() {
+        for forecast in [spring_forward(), fall_back()] {
+            for day in build_day_forecasts(&forecast) {
+                let len = day.hourly_times.len();
+                assert_eq!(day.hourly_temps.len(), len);
+                assert_eq!(day.hourly_feels.len(), len);
+                assert_eq!(day.hourly_pressure.len(), len);
+                assert_eq!(day.hourly_humidity.len(), len);
+                assert_eq!(day.hourly_wind.len(), len);
+            }
+        }
+    }
+
+    #[test]
+    fn window_not_starting_at_midnight() {
+        let mut forecast = spring_forward();
+        let hourly = &mut forecast.hourly;
+        hourly.time.drain(..6);
+        hourly.temperature_2m.drain(..6);
+        hourly.apparent_temperature.drain(..6);
+        hourly.relative_humidity_2m.drain(..6);
+        hourly.surface_pressure.drain(..6);
+        hourly.wind_speed_10m.drain(..6);
+        hourly.wind_direction_10m.drain(..6);
+        hourly.wind_gusts_10m.drain(..6);
+
+        let days = build_day_forecasts(&forecast);
+
+        assert_eq!(days[0].hourly_times.len(), 18);
+        assert_eq!(days[0].hourly_times.first().map(String::as_str), Some("06:00"));
+        assert_eq!(days[0].hourly_temps.first(), Some(&6.0));
+        assert_eq!(days[1].hourly_temps.first(), Some(&24.0));
+    }
+
+    #[test]
+    fn short_daily_arrays_skip_days_instead_of_panicking() {
+        let mut forecast = spring_forward();
+        forecast.daily.weather_code.truncate(2);
+        forecast.daily.temperature_2m_max.truncate(1);
+
+        let days = build_day_forecasts(&forecast);
+
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].date, forecast.daily.time[0]);
+    }
+
+    // Карта запитує перелік кадрів при кожному відкритті — він має братися з кешу
+    #[test]
+    fn radar_frames_come_from_cache_on_repeat() {
+        let http = StubHttp::default().with(
+            "weather-maps.json",
+            200,
+            r#"{"host": "https://tilecache.rainviewer.com", "radar": {"past": [
+                {"time": 1774699200, "path": "/v2/radar/a"},
+                {"time": 1774699800, "path": "/v2/radar/b"}
+            ]}}"#,
+        );
+        let calls = http.calls.clone();
+        let client = stub_client(CachedClient::new(http, MemoryStore::default()).with_background(None));
+
+        for _ in 0..3 {
+            assert_eq!(
+                ready(client.radar_tiles()).expect("кадр радара"),
+                "https://tilecache.rainviewer.com/v2/radar/b/256/{z}/{x}/{y}/2/1_1.png"
+            );
+        }
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn weather_info_keeps_day_and_night_variants() {
+        let info = WeatherInfo::from_code(0);
+
+        assert_eq!((info.icon_code(true), info.icon_code(false)), ("01d", "01n"));
+        assert_eq!(info.icon(true).0, "sun");
+        assert_eq!(info.icon(false).0, "moon");
+        assert_eq!(info.description(false), "Ясна ніч");
+    }
+
+    // Пора доби обчислюється на момент показу, а не запиту
+    #[test]
+    fn is_day_follows_sunrise_and_sunset_of_the_local_day() {
+        let data = ready(stub_client(StubHttp::kyiv()).get_weather("Київ")).expect("погода для Києва");
+        // 2026-03-28 12:00 UTC — 14:00 у Києві (UTC+2); схід 06:30, захід 18:30
+        let noon = 1_774_699_200;
+
+        assert!(data.is_day_at(noon));
+        assert!(!data.is_day_at(noon + 5 * 3600));
+        assert!(!data.is_day_at(noon - 8 * 3600));
+        assert!(data.is_day_at(noon - 7 * 3600));
+        // День без прогнозу — is_day з відповіді
+        assert!(data.is_day_at(noon + 30 * 86_400));
+    }
+
+    #[test]
+    fn uv_band_cut_offs() {
+        for (uv, band) in [
+            (0.0, "низький"),
+            (2.9, "низький"),
+            (3.0, "помірний"),
+            (5.9, "помірний"),
+            (6.0, "високий"),
+            (7.9, "високий"),
+            (8.0, "дуже високий"),
+            (10.9, "дуже високий"),
+            (11.0, "екстремальний"),
+        ] {
+            assert_eq!(uv_band(uv), band, "УФ {}", uv);
+        }
+    }
+
+    #[test]
+    fn visibility_band_cut_offs() {
+        for (meters, band) in [
+            (0.0, "дуже погана"),
+            (999.0, "дуже погана"),
+            (1_000.0, "погана"),
+            (3_999.0, "погана"),
+            (4_000.0, "помірна"),
+            (9_999.0, "помірна"),
+            (10_000.0, "добра"),
+            (19_999.0, "добра"),
+            (20_000.0, "відмінна"),
+        ] {
+            assert_eq!(visibility_band(meters), band, "{} м", meters);
+        }
+    }
+
+    #[test]
+    fn cloud_band_cut_offs() {
+        for (percent, band) in [
+            (0, "ясно"),
+            (10, "ясно"),
+            (11, "мінлива хмарність"),
+            (50, "мінлива хмарність"),
+            (51, "хмарно"),
+            (85, "хмарно"),
+            (86, "суцільна хмарність"),
+            (100, "суцільна хмарність"),
+        ] {
+            assert_eq!(cloud_band(percent), band, "{}%", percent);
+        }
+    }
+
+    #[test]
+    fn dew_point_band_cut_offs() {
+        for (celsius, band) in [
+            (-5.0, "сухо"),
+            (9.9, "сухо"),
+            (10.0, "комфортно"),
+            (15.9, "комфортно"),
+            (16.0, "волого"),
+            (19.9, "волого"),
+            (20.0, "задушливо"),
+        ] {
+            assert_eq!(dew_point_band(celsius), band, "{} °C", celsius);
+        }
+    }
+
+    #[test]
+    fn sampling_uses_clock_hours_not_positions() {
+        let days = build_day_forecasts(&spring_forward());
+
+        let labels: Vec<&str> = days[1]
+            .sample(HourlyResolution::SixHourly)
+            .into_iter()
+            .map(|idx| days[1].hourly_times[idx].as_str())
+            .collect();
+        assert_eq!(labels, ["00:00", "06:00", "12:00", "18:00"]);
+    }
 }
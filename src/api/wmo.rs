@@ -0,0 +1,306 @@
+// Таблиця кодів погоди WMO 4677 у варіанті, який повертає Open-Meteo:
+// https://open-meteo.com/en/docs#weathervariables
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Intensity {
+    Light,
+    Moderate,
+    Heavy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherCode {
+    // 0
+    Clear,
+    // 1
+    MainlyClear,
+    // 2
+    PartlyCloudy,
+    // 3
+    Overcast,
+    // 45
+    Fog,
+    // 48
+    RimeFog,
+    // 51, 53, 55
+    Drizzle(Intensity),
+    // 56, 57 (лише слабка і сильна)
+    FreezingDrizzle(Intensity),
+    // 61, 63, 65
+    Rain(Intensity),
+    // 66, 67 (лише слабкий і сильний)
+    FreezingRain(Intensity),
+    // 71, 73, 75
+    Snow(Intensity),
+    // 77
+    SnowGrains,
+    // 80, 81, 82
+    RainShowers(Intensity),
+    // 85, 86 (лише слабкий і сильний)
+    SnowShowers(Intensity),
+    // 95
+    Thunderstorm,
+    // 96, 99 (лише слабкий і сильний град)
+    ThunderstormHail(Intensity),
+    Unknown(i32),
+}
+
+impl WeatherCode {
+    pub fn from_code(code: i32) -> Self {
+        use Intensity::*;
+        use WeatherCode::*;
+
+        match code {
+            0 => Clear,
+            1 => MainlyClear,
+            2 => PartlyCloudy,
+            3 => Overcast,
+            45 => Fog,
+            48 => RimeFog,
+            51 => Drizzle(Light),
+            53 => Drizzle(Moderate),
+            55 => Drizzle(Heavy),
+            56 => FreezingDrizzle(Light),
+            57 => FreezingDrizzle(Heavy),
+            61 => Rain(Light),
+            63 => Rain(Moderate),
+            65 => Rain(Heavy),
+            66 => FreezingRain(Light),
+            67 => FreezingRain(Heavy),
+            71 => Snow(Light),
+            73 => Snow(Moderate),
+            75 => Snow(Heavy),
+            77 => SnowGrains,
+            80 => RainShowers(Light),
+            81 => RainShowers(Moderate),
+            82 => RainShowers(Heavy),
+            85 => SnowShowers(Light),
+            86 => SnowShowers(Heavy),
+            95 => Thunderstorm,
+            96 => ThunderstormHail(Light),
+            99 => ThunderstormHail(Heavy),
+            other => Unknown(other),
+        }
+    }
+
+    pub fn intensity(self) -> Option<Intensity> {
+        use WeatherCode::*;
+
+        match self {
+            Drizzle(i) | FreezingDrizzle(i) | Rain(i) | FreezingRain(i) | Snow(i) | RainShowers(i)
+            | SnowShowers(i) | ThunderstormHail(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    // Крижана мряка чи крижаний дощ — найнебезпечніші для доріг
+    pub fn is_freezing(self) -> bool {
+        matches!(self, WeatherCode::FreezingDrizzle(_) | WeatherCode::FreezingRain(_))
+    }
+
+    pub fn description(self, is_day: bool) -> &'static str {
+        use Intensity::*;
+        use WeatherCode::*;
+
+        match self {
+            Clear if is_day => "Ясно",
+            Clear => "Ясна ніч",
+            MainlyClear if is_day => "Переважно ясно",
+            MainlyClear => "Переважно ясна ніч",
+            PartlyCloudy => "Мінлива хмарність",
+            Overcast => "Хмарно",
+            Fog => "Туман",
+            RimeFog => "Туман із памороззю",
+            Drizzle(Light) => "Слабка мряка",
+            Drizzle(Moderate) => "Мряка",
+            Drizzle(Heavy) => "Густа мряка",
+            FreezingDrizzle(Light) | FreezingDrizzle(Moderate) => "Слабка крижана мряка",
+            FreezingDrizzle(Heavy) => "Густа крижана мряка",
+            Rain(Light) => "Слабкий дощ",
+            Rain(Moderate) => "Дощ",
+            Rain(Heavy) => "Сильний дощ",
+            FreezingRain(Light) | FreezingRain(Moderate) => "Слабкий крижаний дощ",
+            FreezingRain(Heavy) => "Сильний крижаний дощ",
+            Snow(Light) => "Слабкий сніг",
+            Snow(Moderate) => "Сніг",
+            Snow(Heavy) => "Сильний сніг",
+            SnowGrains => "Снігова крупа",
+            RainShowers(Light) => "Слабкі зливи",
+            RainShowers(Moderate) => "Зливи",
+            RainShowers(Heavy) => "Сильні зливи",
+            SnowShowers(Light) | SnowShowers(Moderate) => "Слабкий снігопад",
+            SnowShowers(Heavy) => "Сильний снігопад",
+            Thunderstorm => "Гроза",
+            ThunderstormHail(Light) | ThunderstormHail(Moderate) => "Гроза з градом",
+            ThunderstormHail(Heavy) => "Гроза з сильним градом",
+            Unknown(_) => "Невідомо",
+        }
+    }
+
+    // Lucide-іконка і колір Tailwind. Уночі сонце замінюємо місяцем
+    pub fn icon(self, is_day: bool) -> (&'static str, &'static str) {
+        use Intensity::*;
+        use WeatherCode::*;
+
+        match self {
+            Clear if is_day => ("sun", "text-yellow-400"),
+            Clear => ("moon", "text-indigo-200"),
+            MainlyClear if is_day => ("cloud-sun", "text-yellow-200"),
+            PartlyCloudy if is_day => ("cloud-sun", "text-gray-300"),
+            MainlyClear | PartlyCloudy => ("cloud-moon", "text-indigo-200"),
+            Overcast => ("cloudy", "text-gray-400"),
+            Fog => ("cloud-fog", "text-gray-300"),
+            RimeFog => ("cloud-fog", "text-cyan-100"),
+            Drizzle(Light) => ("cloud-drizzle", "text-blue-200"),
+            Drizzle(Moderate) => ("cloud-drizzle", "text-blue-300"),
+            Drizzle(Heavy) => ("cloud-drizzle", "text-blue-400"),
+            FreezingDrizzle(Heavy) => ("cloud-drizzle", "text-cyan-300"),
+            FreezingDrizzle(_) => ("cloud-drizzle", "text-cyan-200"),
+            Rain(Light) => ("cloud-rain", "text-blue-300"),
+            Rain(Moderate) => ("cloud-rain", "text-blue-400"),
+            Rain(Heavy) => ("cloud-rain-wind", "text-blue-500"),
+            FreezingRain(Heavy) => ("cloud-rain", "text-cyan-300"),
+            FreezingRain(_) => ("cloud-rain", "text-cyan-200"),
+            Snow(Light) => ("cloud-snow", "text-slate-200"),
+            Snow(Moderate) => ("cloud-snow", "text-white"),
+            Snow(Heavy) => ("snowflake", "text-white"),
+            SnowGrains => ("cloud-hail", "text-blue-100"),
+            RainShowers(Heavy) => ("cloud-rain-wind", "text-blue-600"),
+            RainShowers(Light) if is_day => ("cloud-sun-rain", "text-blue-300"),
+            RainShowers(_) if is_day => ("cloud-sun-rain", "text-blue-500"),
+            RainShowers(Light) => ("cloud-moon-rain", "text-blue-300"),
+            RainShowers(_) => ("cloud-moon-rain", "text-blue-500"),
+            SnowShowers(Heavy) => ("snowflake", "text-slate-100"),
+            SnowShowers(_) => ("cloud-snow", "text-slate-100"),
+            Thunderstorm => ("cloud-lightning", "text-purple-400"),
+            ThunderstormHail(Heavy) => ("cloud-lightning", "text-purple-600"),
+            ThunderstormHail(_) => ("cloud-lightning", "text-purple-500"),
+            Unknown(_) => ("cloud", "text-gray-400"),
+        }
+    }
+
+    // Код іконки у стилі OpenWeatherMap: "10d" вдень, "10n" вночі
+    pub fn icon_code(self, is_day: bool) -> String {
+        use WeatherCode::*;
+
+        let base = match self {
+            Clear | Unknown(_) => "01",
+            MainlyClear => "02",
+            PartlyCloudy => "03",
+            Overcast => "04",
+            Drizzle(_) | RainShowers(_) => "09",
+            Rain(_) => "10",
+            Thunderstorm | ThunderstormHail(_) => "11",
+            FreezingDrizzle(_) | FreezingRain(_) | Snow(_) | SnowGrains | SnowShowers(_) => "13",
+            Fog | RimeFog => "50",
+        };
+
+        format!("{}{}", base, if is_day { "d" } else { "n" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Усі коди з документації Open-Meteo
+    const DOCUMENTED: [i32; 28] = [
+        0, 1, 2, 3, 45, 48, 51, 53, 55, 56, 57, 61, 63, 65, 66, 67, 71, 73, 75, 77, 80, 81, 82, 85,
+        86, 95, 96, 99,
+    ];
+
+    #[test]
+    fn every_documented_code_is_known() {
+        for code in DOCUMENTED {
+            let weather = WeatherCode::from_code(code);
+            assert!(!matches!(weather, WeatherCode::Unknown(_)), "код {}", code);
+            for is_day in [true, false] {
+                assert_ne!(weather.description(is_day), "Невідомо", "код {}", code);
+                assert_ne!(weather.icon(is_day), ("cloud", "text-gray-400"), "код {}", code);
+            }
+        }
+    }
+
+    #[test]
+    fn every_documented_code_has_its_own_description() {
+        for (i, &a) in DOCUMENTED.iter().enumerate() {
+            for &b in &DOCUMENTED[i + 1..] {
+                assert_ne!(
+                    WeatherCode::from_code(a).description(true),
+                    WeatherCode::from_code(b).description(true),
+                    "коди {} і {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn intensity_follows_code() {
+        use Intensity::*;
+
+        let cases = [
+            (51, Light),
+            (53, Moderate),
+            (55, Heavy),
+            (56, Light),
+            (57, Heavy),
+            (61, Light),
+            (63, Moderate),
+            (65, Heavy),
+            (66, Light),
+            (67, Heavy),
+            (71, Light),
+            (73, Moderate),
+            (75, Heavy),
+            (80, Light),
+            (81, Moderate),
+            (82, Heavy),
+            (85, Light),
+            (86, Heavy),
+            (96, Light),
+            (99, Heavy),
+        ];
+        for (code, intensity) in cases {
+            assert_eq!(WeatherCode::from_code(code).intensity(), Some(intensity), "код {}", code);
+        }
+        for code in [0, 1, 2, 3, 45, 48, 77, 95] {
+            assert_eq!(WeatherCode::from_code(code).intensity(), None, "код {}", code);
+        }
+    }
+
+    #[test]
+    fn freezing_variants() {
+        for code in [56, 57, 66, 67] {
+            assert!(WeatherCode::from_code(code).is_freezing(), "код {}", code);
+        }
+        for code in [51, 55, 61, 65, 71, 80] {
+            assert!(!WeatherCode::from_code(code).is_freezing(), "код {}", code);
+        }
+        assert_eq!(WeatherCode::from_code(57).description(true), "Густа крижана мряка");
+        assert_eq!(WeatherCode::from_code(66).description(true), "Слабкий крижаний дощ");
+    }
+
+    #[test]
+    fn night_variants() {
+        assert_eq!(WeatherCode::from_code(0).icon(false).0, "moon");
+        assert_eq!(WeatherCode::from_code(2).icon(false).0, "cloud-moon");
+        assert_eq!(WeatherCode::from_code(80).icon(false).0, "cloud-moon-rain");
+        assert_eq!(WeatherCode::from_code(0).description(false), "Ясна ніч");
+        assert_eq!(WeatherCode::from_code(63).icon_code(false), "10n");
+        assert_eq!(WeatherCode::from_code(63).icon_code(true), "10d");
+    }
+
+    #[test]
+    fn unknown_code_falls_back() {
+        for code in [-1, 4, 50, 62, 100] {
+            let weather = WeatherCode::from_code(code);
+            assert_eq!(weather, WeatherCode::Unknown(code));
+            assert_eq!(weather.description(true), "Невідомо");
+            assert_eq!(weather.icon(true), ("cloud", "text-gray-400"));
+            assert_eq!(weather.icon_code(true), "01d");
+            assert_eq!(weather.intensity(), None);
+        }
+    }
+}
@@ -0,0 +1,348 @@
+// Консольний клієнт: ті самі дані, що й DetailedCard у веб-застосунку.
+//
+//   cargo run --no-default-features --features cli -- Черкаси
+//   cargo run --no-default-features --features cli -- --lat 49.44 --lon 32.06 --day 1
+//   cargo run --no-default-features --features cli -- Оржиця --json
+
+use std::process::ExitCode;
+
+use weather::api::time::now_unix;
+use weather::api::units::Units;
+use weather::api::weather::{cloud_band, dew_point_band, uv_band, visibility_band};
+use weather::{ForecastRange, GeoLocation, HourlyResolution, WeatherClient, WeatherData};
+
+const USAGE: &str = "\
+Використання:
+  weather <місто> [--days 7|10|16] [--day N] [--step 1|3|6] [--imperial] [--json]
+  weather --lat <широта> --lon <довгота> [--days 7|10|16] [--day N] [--step 1|3|6] [--imperial] [--json]
+
+  --days N     тривалість прогнозу: 7 (типово), 10 або 16 днів
+  --day N      день прогнозу для погодинної таблиці (0 — сьогодні)
+  --step N     крок погодинної таблиці в годинах: 1, 3 (типово) або 6
+  --imperial   °F, миль/год, дюйми рт. ст. замість °C, м/с, мм рт. ст.
+  --json       вивести WeatherData у форматі JSON (завжди °C, км/год, гПа)";
+
+enum Query {
+    City(String),
+    Coords(f64, f64),
+}
+
+struct Args {
+    query: Query,
+    range: ForecastRange,
+    day: usize,
+    resolution: HourlyResolution,
+    units: Units,
+    json: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut city: Vec<String> = Vec::new();
+    let mut lat = None;
+    let mut lon = None;
+    let mut range = ForecastRange::default();
+    let mut day = 0;
+    let mut resolution = HourlyResolution::default();
+    let mut units = Units::default();
+    let mut json = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--imperial" => units = Units::imperial(),
+            "--lat" | "--lon" | "--days" | "--day" | "--step" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("{} потребує значення", arg))?;
+                match arg.as_str() {
+                    "--lat" => lat = Some(parse_number(&arg, &value)?),
+                    "--lon" => lon = Some(parse_number(&arg, &value)?),
+                    "--days" => {
+                        range = ForecastRange::ALL
+                            .into_iter()
+                            .find(|r| r.days().to_string() == value)
+                            .ok_or_else(|| format!("Некоректна тривалість прогнозу: {}", value))?
+                    }
+                    "--step" => {
+                        resolution = HourlyResolution::ALL
+                            .into_iter()
+                            .find(|r| r.step().to_string() == value)
+                            .ok_or_else(|| format!("Некоректний крок: {}", value))?
+                    }
+                    _ => day = value.parse().map_err(|_| format!("Некоректний день: {}", value))?,
+                }
+            }
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with("--") => return Err(format!("Невідомий параметр: {}", arg)),
+            _ => city.push(arg),
+        }
+    }
+
+    let query = match (lat, lon) {
+        (Some(lat), Some(lon)) if city.is_empty() => Query::Coords(lat, lon),
+        (None, None) if !city.is_empty() => Query::City(city.join(" ")),
+        _ => return Err("Вкажіть або назву міста, або --lat і --lon".to_string()),
+    };
+
+    Ok(Args { query, range, day, resolution, units, json })
+}
+
+fn parse_number(flag: &str, value: &str) -> Result<f64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Некоректне значення {}: {}", flag, value))
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            if !message.is_empty() {
+                eprintln!("{}\n", message);
+            }
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let client = WeatherClient::default();
+    let result = pollster::block_on(async {
+        let location = match args.query {
+            Query::City(ref city) => client.get_coordinates(city).await?,
+            // Координати не передаємо сторонньому зворотному геокодеру
+            Query::Coords(lat, lon) => GeoLocation::from_coords(lat, lon),
+        };
+        client.get_weather_for_range(location, args.range).await
+    });
+
+    let data = match result {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Помилка: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.json {
+        match serde_json::to_string_pretty(&data) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Помилка серіалізації: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        print_report(&data, args.day, args.resolution, args.units);
+    }
+
+    if let Some(err) = &data.forecast_error {
+        eprintln!("Увага: {}", err);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn print_report(data: &WeatherData, day_idx: usize, resolution: HourlyResolution, units: Units) {
+    let region = data.region();
+    if region.is_empty() {
+        println!("{}", data.name);
+    } else {
+        println!("{}, {}", data.name, region);
+    }
+    let now = now_unix();
+    let local = data.local_time(now);
+    if data.timezone.is_empty() {
+        println!("Місцевий час: {} {}", local.date(), local.clock());
+    } else {
+        println!("Місцевий час: {} {} ({})", local.date(), local.clock(), data.timezone);
+    }
+    let temperature = units.temperature;
+    println!(
+        "Зараз: {:+.0}{} (відчувається як {:+.0}{}), {}",
+        temperature.convert(data.main.temp),
+        temperature.label(),
+        temperature.convert(data.main.feels_like),
+        temperature.label(),
+        data.weather.description(data.is_day_at(now))
+    );
+    let conditions: Vec<String> = [
+        data.visibility.map(|m| format!("видимість {:.1} км ({})", m / 1000.0, visibility_band(m))),
+        data.clouds.map(|c| format!("хмарність {}% ({})", c, cloud_band(c))),
+        data.uv_index.map(|uv| format!("УФ-індекс {:.1} ({})", uv, uv_band(uv))),
+        data.main.dew_point.map(|t| {
+            format!("точка роси {:+.0}{} ({})", temperature.convert(t), temperature.label(), dew_point_band(t))
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !conditions.is_empty() {
+        println!("Умови: {}", conditions.join(", "));
+    }
+
+    let Some(day) = data.forecast.as_ref().and_then(|days| days.get(day_idx)) else {
+        if data.forecast_error.is_none() {
+            eprintln!("Дані для цього дня не знайдено");
+        }
+        return;
+    };
+
+    println!("{}: {}", day.day_name, day.description);
+    println!(
+        "Схід: {}   Захід: {}",
+        day.sunrise.as_deref().unwrap_or("Невідомо"),
+        day.sunset.as_deref().unwrap_or("Невідомо")
+    );
+    match day.precipitation.probability {
+        Some(probability) => println!(
+            "Опади: {:.1} мм, ймовірність {}% ({})",
+            day.precipitation.total,
+            probability,
+            day.precipitation.breakdown()
+        ),
+        None => println!("Опади: {:.1} мм ({})", day.precipitation.total, day.precipitation.breakdown()),
+    }
+    println!();
+
+    if day.step_hours() > resolution.step() {
+        println!("Для цього дня дані є з кроком {} год", day.step_hours());
+        println!();
+    }
+
+    // Рядки будуємо для всіх годин дня, а друкуємо лише вибрані роздільністю колонки
+    let hours = day.sample(resolution);
+    let pick = |cells: &[String]| -> Vec<String> { hours.iter().filter_map(|&i| cells.get(i).cloned()).collect() };
+    let rows: [(String, Vec<String>); 10] = [
+        (
+            format!("Температура, {}", temperature.label()),
+            day.hourly_temps.iter().map(|&t| temperature.format(t)).collect(),
+        ),
+        (
+            "Відчувається як".to_string(),
+            day.hourly_feels.iter().map(|&t| temperature.format(t)).collect(),
+        ),
+        (
+            format!("Тиск, {}", units.pressure.label()),
+            day.hourly_pressure.iter().map(|&p| units.pressure.format(p)).collect(),
+        ),
+        (
+            "Вологість, %".to_string(),
+            day.hourly_humidity.iter().map(|h| h.to_string()).collect(),
+        ),
+        (
+            "Опади, мм".to_string(),
+            day.hourly_precipitation.iter().map(|p| format!("{:.1}", p.total)).collect(),
+        ),
+        (
+            "Ймовірність опадів, %".to_string(),
+            day.hourly_precipitation
+                .iter()
+                .map(|p| p.probability.map(|v| v.to_string()).unwrap_or_else(|| "—".to_string()))
+                .collect(),
+        ),
+        (
+            "Хмарність, %".to_string(),
+            day.hourly_conditions
+                .iter()
+                .map(|c| c.cloud_cover.map(|v| v.to_string()).unwrap_or_else(|| "—".to_string()))
+                .collect(),
+        ),
+        (
+            "УФ-індекс".to_string(),
+            day.hourly_conditions
+                .iter()
+                .map(|c| c.uv_index.map(|v| format!("{:.0}", v)).unwrap_or_else(|| "—".to_string()))
+                .collect(),
+        ),
+        (
+            format!("Вітер, {}", units.wind.label()),
+            day.hourly_wind.iter().map(|w| format!("{}{}", units.wind.format(w.speed), w.arrow())).collect(),
+        ),
+        (
+            format!("Пориви, {}", units.wind.label()),
+            day.hourly_wind
+                .iter()
+                .map(|w| w.gusts.map(|g| units.wind.format(g)).unwrap_or_else(|| "—".to_string()))
+                .collect(),
+        ),
+    ];
+
+    print_row(&day.day_name, &pick(&day.hourly_times));
+    for (label, cells) in rows.iter() {
+        print_row(label, &pick(cells));
+    }
+}
+
+fn print_row(label: &str, cells: &[String]) {
+    let mut line = format!("{:<24}", label);
+    for cell in cells {
+        line.push_str(&format!("{:>7}", cell));
+    }
+    println!("{}", line.trim_end());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn error(args: &[&str]) -> String {
+        match parse(args) {
+            Ok(_) => panic!("{:?} мали б дати помилку", args),
+            Err(message) => message,
+        }
+    }
+
+    #[test]
+    fn multi_word_city_is_joined_with_spaces() {
+        let args = parse(&["Біла", "Церква", "--day", "2", "--json"]).expect("коректні аргументи");
+
+        assert!(matches!(args.query, Query::City(ref city) if city == "Біла Церква"));
+        assert_eq!(args.day, 2);
+        assert!(args.json);
+        assert_eq!(args.range, ForecastRange::Week);
+        assert_eq!(args.resolution, HourlyResolution::ThreeHourly);
+    }
+
+    #[test]
+    fn coords_and_options() {
+        let args = parse(&["--lat", "49.44", "--lon", "-32.06", "--days", "16", "--step", "1"]).expect("коректні аргументи");
+
+        assert!(matches!(args.query, Query::Coords(lat, lon) if lat == 49.44 && lon == -32.06));
+        assert_eq!(args.range, ForecastRange::SixteenDays);
+        assert_eq!(args.resolution, HourlyResolution::Hourly);
+    }
+
+    #[test]
+    fn city_or_both_coords_are_required() {
+        let expected = "Вкажіть або назву міста, або --lat і --lon";
+
+        assert_eq!(error(&["--lat", "1"]), expected);
+        assert_eq!(error(&["--lon", "2"]), expected);
+        assert_eq!(error(&["Київ", "--lat", "1", "--lon", "2"]), expected);
+        assert_eq!(error(&[]), expected);
+    }
+
+    #[test]
+    fn bad_values_are_rejected() {
+        assert_eq!(error(&["Київ", "--days", "9"]), "Некоректна тривалість прогнозу: 9");
+        assert_eq!(error(&["Київ", "--step", "2"]), "Некоректний крок: 2");
+        assert_eq!(error(&["Київ", "--day", "-1"]), "Некоректний день: -1");
+        assert_eq!(error(&["--lat", "пн", "--lon", "2"]), "Некоректне значення --lat: пн");
+        assert_eq!(error(&["Київ", "--metric"]), "Невідомий параметр: --metric");
+    }
+
+    #[test]
+    fn flag_without_value_is_an_error() {
+        assert_eq!(error(&["Київ", "--step"]), "--step потребує значення");
+        assert_eq!(error(&["--lat"]), "--lat потребує значення");
+    }
+
+    #[test]
+    fn help_asks_for_usage_only() {
+        assert_eq!(error(&["Київ", "--help"]), "");
+    }
+}
@@ -0,0 +1,179 @@
+use leptos::*;
+
+use crate::api::units::Units;
+use crate::api::weather::DayForecast;
+
+// Розміри у координатах viewBox; на сторінці графік розтягується на ширину картки
+const WIDTH: f64 = 720.0;
+const HEIGHT: f64 = 240.0;
+const PAD_LEFT: f64 = 36.0;
+const PAD_RIGHT: f64 = 12.0;
+// Смуга температур і смуга опадів під нею
+const TEMP_TOP: f64 = 16.0;
+const TEMP_BOTTOM: f64 = 150.0;
+const BARS_TOP: f64 = 165.0;
+const BARS_BOTTOM: f64 = 215.0;
+// Шкала опадів не дрібніша за 2 мм, щоб слабкий дощ не виглядав зливою
+const MIN_PRECIPITATION_SCALE: f64 = 2.0;
+
+fn x_at(idx: usize, count: usize) -> f64 {
+    let span = WIDTH - PAD_LEFT - PAD_RIGHT;
+    if count < 2 {
+        PAD_LEFT + span / 2.0
+    } else {
+        PAD_LEFT + span * idx as f64 / (count - 1) as f64
+    }
+}
+
+fn line_path(values: &[f64], y: impl Fn(f64) -> f64) -> String {
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, &v)| format!("{}{:.1},{:.1}", if idx == 0 { "M" } else { "L" }, x_at(idx, values.len()), y(v)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Погодинний графік дня: температура, відчувається як і стовпчики опадів.
+// current — індекс поточної години в day.hourly_times (лише для сьогодні)
+#[component]
+pub fn HourlyChart(day: DayForecast, units: ReadSignal<Units>, current: Option<usize>) -> impl IntoView {
+    let (hovered, set_hovered) = create_signal(None::<usize>);
+
+    view! {
+        <svg
+            viewBox=format!("0 0 {} {}", WIDTH, HEIGHT)
+            class="w-full h-auto select-none"
+            on:mouseleave=move |_| set_hovered.set(None)
+        >
+            {move || {
+                let temperature = units.get().temperature;
+                let temps: Vec<f64> = day.hourly_temps.iter().map(|&t| temperature.convert(t)).collect();
+                let feels: Vec<f64> = day.hourly_feels.iter().map(|&t| temperature.convert(t)).collect();
+                let count = temps.len();
+                if count == 0 {
+                    return view! {
+                        <text x=WIDTH / 2.0 y=HEIGHT / 2.0 text-anchor="middle" class="fill-gray-400 text-sm">
+                            "Немає погодинних даних"
+                        </text>
+                    }.into_view();
+                }
+
+                let min = temps.iter().chain(&feels).copied().fold(f64::INFINITY, f64::min).floor() - 1.0;
+                let max = temps.iter().chain(&feels).copied().fold(f64::NEG_INFINITY, f64::max).ceil() + 1.0;
+                let y = move |v: f64| TEMP_BOTTOM - (v - min) / (max - min) * (TEMP_BOTTOM - TEMP_TOP);
+
+                let precipitation_scale = day
+                    .hourly_precipitation
+                    .iter()
+                    .map(|p| p.total)
+                    .fold(MIN_PRECIPITATION_SCALE, f64::max);
+                let column = (WIDTH - PAD_LEFT - PAD_RIGHT) / count.max(1) as f64;
+
+                let bars = day.hourly_precipitation.iter().enumerate().filter(|(_, p)| p.total > 0.0).map(|(idx, p)| {
+                    let height = p.total / precipitation_scale * (BARS_BOTTOM - BARS_TOP);
+                    view! {
+                        <rect
+                            x=x_at(idx, count) - column * 0.35
+                            y=BARS_BOTTOM - height
+                            width=column * 0.7
+                            height=height
+                            rx="1.5"
+                            class="fill-blue-400/60"
+                        />
+                    }
+                }).collect::<Vec<_>>();
+
+                let hour_labels = day.hourly_times.iter().enumerate()
+                    .filter(|(_, time)| crate::api::weather::hour_of(time).is_some_and(|h| h % 3 == 0))
+                    .map(|(idx, time)| view! {
+                        <text x=x_at(idx, count) y=HEIGHT - 6.0 text-anchor="middle" class="fill-gray-400 text-[10px]">
+                            {time.clone()}
+                        </text>
+                    }).collect::<Vec<_>>();
+
+                // Прозорі смуги на всю висоту ловлять наведення для кожної години
+                let hover_zones = (0..count).map(|idx| view! {
+                    <rect
+                        x=x_at(idx, count) - column / 2.0
+                        y="0"
+                        width=column
+                        height=HEIGHT
+                        fill="transparent"
+                        on:mouseenter=move |_| set_hovered.set(Some(idx))
+                    />
+                }).collect::<Vec<_>>();
+
+                let marker = current.filter(|&idx| idx < count).map(|idx| view! {
+                    <line
+                        x1=x_at(idx, count) x2=x_at(idx, count)
+                        y1=TEMP_TOP y2=BARS_BOTTOM
+                        class="stroke-blue-200"
+                        stroke-width="1.5"
+                        stroke-dasharray="4 3"
+                    />
+                    <text x=x_at(idx, count) y=TEMP_TOP - 4.0 text-anchor="middle" class="fill-blue-200 text-[10px]">
+                        "зараз"
+                    </text>
+                });
+
+                let tooltip = {
+                    let temps = temps.clone();
+                    let feels = feels.clone();
+                    let day = day.clone();
+                    move || {
+                        let idx = hovered.get().filter(|&idx| idx < count)?;
+                        let x = x_at(idx, count);
+                        // Підказка праворуч від точки, а біля правого краю — ліворуч
+                        let box_x = if x + 150.0 > WIDTH { x - 142.0 } else { x + 8.0 };
+                        let precipitation = day.hourly_precipitation.get(idx).copied().unwrap_or_default();
+                        let lines = [
+                            day.hourly_times.get(idx).cloned().unwrap_or_default(),
+                            format!("Температура: {:+.0}{}", temps[idx], temperature.label()),
+                            format!("Відчувається: {:+.0}{}", feels.get(idx).copied().unwrap_or(temps[idx]), temperature.label()),
+                            match precipitation.probability {
+                                Some(p) => format!("Опади: {:.1} мм, {}%", precipitation.total, p),
+                                None => format!("Опади: {:.1} мм", precipitation.total),
+                            },
+                        ];
+                        Some(view! {
+                            <g class="pointer-events-none">
+                                <line x1=x x2=x y1=TEMP_TOP y2=BARS_BOTTOM class="stroke-gray-500" stroke-width="1"/>
+                                <circle cx=x cy=y(temps[idx]) r="4" class="fill-orange-300"/>
+                                {feels.get(idx).map(|&f| view! { <circle cx=x cy=y(f) r="3.5" class="fill-blue-200"/> })}
+                                <rect x=box_x y=TEMP_TOP width="134" height="64" rx="6" class="fill-[#2D2F31] stroke-[#43474E]"/>
+                                {lines.into_iter().enumerate().map(|(row, text)| view! {
+                                    <text x=box_x + 8.0 y=TEMP_TOP + 15.0 + row as f64 * 14.0 class="fill-gray-200 text-[11px]">
+                                        {text}
+                                    </text>
+                                }).collect::<Vec<_>>()}
+                            </g>
+                        })
+                    }
+                };
+
+                view! {
+                    <text x=PAD_LEFT - 6.0 y=TEMP_TOP + 4.0 text-anchor="end" class="fill-gray-400 text-[10px]">
+                        {format!("{:+.0}°", max)}
+                    </text>
+                    <text x=PAD_LEFT - 6.0 y=TEMP_BOTTOM text-anchor="end" class="fill-gray-400 text-[10px]">
+                        {format!("{:+.0}°", min)}
+                    </text>
+                    <line x1=PAD_LEFT x2=WIDTH - PAD_RIGHT y1=BARS_BOTTOM y2=BARS_BOTTOM class="stroke-[#43474E]" stroke-width="1"/>
+                    {bars}
+                    <path d=line_path(&feels, y) fill="none" class="stroke-blue-200" stroke-width="2" stroke-dasharray="5 4"/>
+                    <path d=line_path(&temps, y) fill="none" class="stroke-orange-300" stroke-width="2.5"/>
+                    {hour_labels}
+                    {marker}
+                    {hover_zones}
+                    {tooltip}
+                }.into_view()
+            }}
+        </svg>
+        <div class="flex gap-6 mt-2 text-xs text-gray-400">
+            <span class="flex items-center gap-2"><span class="inline-block w-4 h-0.5 bg-orange-300"></span>"Температура"</span>
+            <span class="flex items-center gap-2"><span class="inline-block w-4 h-0.5 border-t-2 border-dashed border-blue-200"></span>"Відчувається як"</span>
+            <span class="flex items-center gap-2"><span class="inline-block w-3 h-3 rounded-sm bg-blue-400/60"></span>"Опади, мм"</span>
+        </div>
+    }
+}
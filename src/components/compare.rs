@@ -0,0 +1,174 @@
+use leptos::*;
+
+use crate::api::error::WeatherError;
+use crate::api::time::now_unix;
+use crate::api::units::Units;
+use crate::api::weather::{get_weather_for, GeoLocation, WeatherData};
+use crate::components::weather_app::createIcons;
+
+// Скільки днів прогнозу порівнюємо в кожній картці
+const COMPARE_DAYS: usize = 5;
+
+// Обрані локації поруч: кожна картка завантажує погоду сама, тож усі запити йдуть
+// одночасно, а помилка однієї локації не ховає решту
+#[component]
+pub fn CompareDashboard<P, C>(
+    locations: ReadSignal<Vec<GeoLocation>>,
+    units: ReadSignal<Units>,
+    on_pick: P,
+    on_close: C,
+) -> impl IntoView
+where
+    P: Fn(GeoLocation) + Copy + 'static,
+    C: Fn() + Copy + 'static,
+{
+    view! {
+        <section class="mb-8">
+            <div class="flex justify-between items-center gap-4 mb-6">
+                <h2 class="text-2xl font-medium">"Порівняння обраних"</h2>
+                <button
+                    class="bg-[#2D2F31] hover:bg-[#333537] text-white font-medium px-5 py-2 rounded-full transition-colors"
+                    on:click=move |_| on_close()
+                >
+                    "Закрити"
+                </button>
+            </div>
+
+            <Show
+                when=move || locations.with(|list| !list.is_empty())
+                fallback=|| view! {
+                    <p class="text-gray-400">"Додайте локації до обраного зірочкою біля назви міста, щоб порівняти їх тут"</p>
+                }
+            >
+                <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">
+                    <For
+                        each=move || locations.get()
+                        key=|location| format!("{:.4},{:.4}", location.latitude, location.longitude)
+                        children=move |location| view! { <CompareCard location=location units=units on_pick=on_pick/> }
+                    />
+                </div>
+            </Show>
+        </section>
+    }
+}
+
+#[component]
+fn CompareCard<P>(location: GeoLocation, units: ReadSignal<Units>, on_pick: P) -> impl IntoView
+where
+    P: Fn(GeoLocation) + Copy + 'static,
+{
+    // None — ще завантажується
+    let (result, set_result) = create_signal(None::<Result<WeatherData, WeatherError>>);
+    let location = store_value(location);
+
+    let fetch = move || {
+        set_result.set(None);
+        spawn_local(async move {
+            set_result.set(Some(get_weather_for(location.get_value()).await));
+            request_animation_frame(move || {
+                createIcons();
+            });
+        });
+    };
+    fetch();
+
+    view! {
+        <div class="m3-card p-6 flex flex-col gap-4">
+            <div>
+                <button
+                    class="text-xl font-medium hover:text-blue-300 text-left"
+                    title="Відкрити детальний прогноз"
+                    on:click=move |_| on_pick(location.get_value())
+                >
+                    {location.with_value(|l| l.name.clone())}
+                </button>
+                <p class="text-sm text-gray-400">{location.with_value(|l| l.region())}</p>
+            </div>
+
+            {move || match result.get() {
+                None => view! {
+                    <div class="flex items-center gap-3 py-8 text-gray-400">
+                        <div class="spinner"></div>
+                        "Завантаження..."
+                    </div>
+                }.into_view(),
+                Some(Err(e)) => {
+                    let retryable = e.is_retryable();
+                    view! {
+                        <div class="bg-red-900 bg-opacity-20 border border-red-700 text-red-300 px-4 py-3 rounded-2xl text-sm">
+                            <p>{e.to_string()}</p>
+                            {retryable.then(|| view! {
+                                <button class="mt-2 underline hover:text-white" on:click=move |_| fetch()>
+                                    "Повторити"
+                                </button>
+                            })}
+                        </div>
+                    }.into_view()
+                }
+                Some(Ok(data)) => view! { <CompareSummary data=data units=units/> }.into_view(),
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn CompareSummary(data: WeatherData, units: ReadSignal<Units>) -> impl IntoView {
+    let temp = data.main.temp;
+    let feels_like = data.main.feels_like;
+    let wind = data.wind.speed;
+    let days = data.forecast.clone().unwrap_or_default();
+    let is_day = data.is_day_at(now_unix());
+    let (icon, icon_color) = data.weather.icon(is_day);
+
+    view! {
+        <div class="flex items-center gap-4">
+            <i data-lucide=icon class={format!("w-12 h-12 {}", icon_color)}></i>
+            <div>
+                <p class="text-4xl font-bold">{move || units.get().temperature.format(temp)}</p>
+                <p class="text-gray-300">{data.weather.description(is_day)}</p>
+            </div>
+        </div>
+        <div class="grid grid-cols-3 gap-2 text-sm">
+            <div>
+                <p class="text-gray-500">"Відчувається"</p>
+                <p>{move || units.get().temperature.format(feels_like)}</p>
+            </div>
+            <div>
+                <p class="text-gray-500">"Вологість"</p>
+                <p>{format!("{}%", data.main.humidity)}</p>
+            </div>
+            <div>
+                <p class="text-gray-500">"Вітер"</p>
+                <p>{move || format!("{} {}", units.get().wind.format(wind), units.get().wind.label())}</p>
+            </div>
+        </div>
+
+        {data.forecast_error.as_ref().map(|err| view! {
+            <p class="text-sm text-yellow-200">{err.to_string()}</p>
+        })}
+
+        <table class="w-full text-sm">
+            <tbody>
+                {days.into_iter().take(COMPARE_DAYS).map(|day| {
+                    let (min, max) = (day.temp_min, day.temp_max);
+                    view! {
+                        <tr class="border-t border-[#43474E]">
+                            <td class="py-2 text-gray-400">{day.day_name}</td>
+                            <td class="py-2" title=day.description>
+                                <i data-lucide={day.icon} class={format!("w-5 h-5 {}", day.icon_color)}></i>
+                            </td>
+                            <td class="py-2 text-right text-blue-300" title="Ймовірність опадів">
+                                {day.precipitation.probability.map(|p| format!("{}%", p))}
+                            </td>
+                            <td class="py-2 text-right">
+                                <span class="text-gray-400" title="Мінімум">{move || units.get().temperature.format(min)}</span>
+                                " / "
+                                <span class="font-bold" title="Максимум">{move || units.get().temperature.format(max)}</span>
+                            </td>
+                        </tr>
+                    }
+                }).collect::<Vec<_>>()}
+            </tbody>
+        </table>
+    }
+}
@@ -0,0 +1,111 @@
+use leptos::*;
+
+use crate::api::weather::GeoLocation;
+use crate::components::storage;
+
+const FAVORITES_KEY: &str = "weather.favorites";
+
+// Початковий набір для нового користувача — колишні фіксовані чипи
+fn default_favorites() -> Vec<GeoLocation> {
+    vec![
+        GeoLocation {
+            name: "Оржиця".to_string(),
+            latitude: 49.7912,
+            longitude: 32.6932,
+            country: "Україна".to_string(),
+            admin1: Some("Полтавська область".to_string()),
+            population: None,
+        },
+        GeoLocation {
+            name: "Черкаси".to_string(),
+            latitude: 49.4445,
+            longitude: 32.0597,
+            country: "Україна".to_string(),
+            admin1: Some("Черкаська область".to_string()),
+            population: None,
+        },
+    ]
+}
+
+pub fn load_favorites() -> Vec<GeoLocation> {
+    storage::load(FAVORITES_KEY).unwrap_or_else(default_favorites)
+}
+
+pub fn save_favorites(favorites: &[GeoLocation]) {
+    storage::save(FAVORITES_KEY, &favorites);
+}
+
+// Додає локацію до обраного або прибирає, якщо вона там уже є
+pub fn toggle_favorite(favorites: &mut Vec<GeoLocation>, location: GeoLocation) {
+    match favorites.iter().position(|f| f.same_place(&location)) {
+        Some(idx) => {
+            favorites.remove(idx);
+        }
+        None => favorites.push(location),
+    }
+}
+
+#[component]
+pub fn FavoriteChips<P, C>(
+    favorites: ReadSignal<Vec<GeoLocation>>,
+    set_favorites: WriteSignal<Vec<GeoLocation>>,
+    on_pick: P,
+    on_compare: C,
+) -> impl IntoView
+where
+    P: Fn(GeoLocation) + Copy + 'static,
+    C: Fn() + Copy + 'static,
+{
+    let move_by = move |idx: usize, delta: isize| {
+        set_favorites.update(|list| {
+            let target = idx as isize + delta;
+            if target >= 0 && (target as usize) < list.len() {
+                list.swap(idx, target as usize);
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-wrap gap-x-4 gap-y-1 mt-2 px-2 text-sm text-gray-400">
+            {move || {
+                let list = favorites.get();
+                let last = list.len().saturating_sub(1);
+                list.into_iter().enumerate().map(|(idx, location)| {
+                    let name = location.name.clone();
+                    let region = location.region();
+                    view! {
+                        <span class="group inline-flex items-center gap-1">
+                            <button
+                                class="opacity-0 group-hover:opacity-100 hover:text-white disabled:invisible"
+                                title="Перемістити ліворуч"
+                                disabled={idx == 0}
+                                on:click=move |_| move_by(idx, -1)
+                            >"‹"</button>
+                            <span
+                                class="hover:text-blue-300 cursor-pointer"
+                                title=region
+                                on:click=move |_| on_pick(location.clone())
+                            >{name}</span>
+                            <button
+                                class="opacity-0 group-hover:opacity-100 hover:text-white disabled:invisible"
+                                title="Перемістити праворуч"
+                                disabled={idx == last}
+                                on:click=move |_| move_by(idx, 1)
+                            >"›"</button>
+                            <button
+                                class="opacity-0 group-hover:opacity-100 hover:text-red-300"
+                                title="Видалити з обраного"
+                                on:click=move |_| set_favorites.update(|list| { list.remove(idx); })
+                            >"×"</button>
+                        </span>
+                    }
+                }).collect::<Vec<_>>()
+            }}
+            {move || (favorites.with(Vec::len) > 1).then(|| view! {
+                <button class="text-blue-300 hover:text-blue-200" on:click=move |_| on_compare()>
+                    "Порівняти"
+                </button>
+            })}
+        </div>
+    }
+}
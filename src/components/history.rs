@@ -0,0 +1,21 @@
+use crate::api::weather::GeoLocation;
+use crate::components::storage;
+
+const HISTORY_KEY: &str = "weather.history";
+// Скільки останніх пошуків зберігаємо
+const HISTORY_LIMIT: usize = 8;
+
+pub fn load_history() -> Vec<GeoLocation> {
+    storage::load(HISTORY_KEY).unwrap_or_default()
+}
+
+pub fn save_history(history: &[GeoLocation]) {
+    storage::save(HISTORY_KEY, &history);
+}
+
+// Переносить локацію на початок історії, прибираючи дублікати та найстаріші записи
+pub fn remember(history: &mut Vec<GeoLocation>, location: GeoLocation) {
+    history.retain(|h| !h.same_place(&location));
+    history.insert(0, location);
+    history.truncate(HISTORY_LIMIT);
+}
@@ -0,0 +1,268 @@
+use std::f64::consts::PI;
+
+use leptos::*;
+
+use crate::api::error::WeatherError;
+use crate::api::weather::{radar_tiles, GeoLocation, WeatherClient};
+
+const TILE_SIZE: f64 = 256.0;
+pub const DEFAULT_ZOOM: u8 = 7;
+const MIN_ZOOM: u8 = 3;
+const MAX_ZOOM: u8 = 12;
+// RainViewer віддає радар лише до цього масштабу; глибше розтягуємо його тайли
+const RADAR_MAX_ZOOM: u8 = 7;
+const MAP_HEIGHT: f64 = 360.0;
+// Половина ширини, яку вкриваємо тайлами: з запасом для max-w-6xl
+const HALF_WIDTH: f64 = 640.0;
+// Зсув курсору в пікселях, після якого натискання — перетягування, а не клік
+const DRAG_THRESHOLD: f64 = 4.0;
+// Запас тайлів навколо видимої області: під час перетягування тайли не
+// перераховуються, і край карти не повинен оголюватися одразу
+const PAN_MARGIN: f64 = TILE_SIZE;
+
+// Web Mercator: градуси -> «світові» пікселі на масштабі zoom
+fn project(lat: f64, lon: f64, zoom: u8) -> (f64, f64) {
+    let scale = TILE_SIZE * f64::from(1u32 << zoom);
+    let lat = lat.clamp(-85.0511, 85.0511).to_radians();
+    let x = (lon + 180.0) / 360.0 * scale;
+    let y = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / PI) / 2.0 * scale;
+    (x, y)
+}
+
+fn unproject(x: f64, y: f64, zoom: u8) -> (f64, f64) {
+    let scale = TILE_SIZE * f64::from(1u32 << zoom);
+    let lon = (x / scale * 360.0 + 180.0).rem_euclid(360.0) - 180.0;
+    let lat = (PI - 2.0 * PI * y / scale).sinh().atan().to_degrees();
+    (lat, lon)
+}
+
+fn tile_url(template: &str, z: u8, x: i64, y: i64) -> String {
+    template
+        .replace("{z}", &z.to_string())
+        .replace("{x}", &x.to_string())
+        .replace("{y}", &y.to_string())
+}
+
+// Тайли шару template масштабу tiles_zoom, що вкривають видиму область навколо
+// центру (cx, cy) у пікселях масштабу zoom. Позиції — відносно центру вікна карти
+fn tile_layer(template: &str, tiles_zoom: u8, zoom: u8, (cx, cy): (f64, f64), class: &'static str) -> Vec<View> {
+    let size = TILE_SIZE * f64::from(1u32 << (zoom - tiles_zoom));
+    let count = 1i64 << tiles_zoom;
+    let (half_width, half_height) = (HALF_WIDTH + PAN_MARGIN, MAP_HEIGHT / 2.0 + PAN_MARGIN);
+    let first_x = ((cx - half_width) / size).floor() as i64;
+    let last_x = ((cx + half_width) / size).floor() as i64;
+    let first_y = (((cy - half_height) / size).floor() as i64).max(0);
+    let last_y = (((cy + half_height) / size).floor() as i64).min(count - 1);
+
+    let mut tiles = Vec::new();
+    for y in first_y..=last_y {
+        for x in first_x..=last_x {
+            let style = format!(
+                "left: calc(50% + {:.0}px); top: calc(50% + {:.0}px); width: {:.0}px; height: {:.0}px",
+                x as f64 * size - cx,
+                y as f64 * size - cy,
+                size,
+                size,
+            );
+            tiles.push(view! {
+                <img
+                    src=tile_url(template, tiles_zoom, x.rem_euclid(count), y)
+                    class=format!("absolute max-w-none pointer-events-none select-none {}", class)
+                    style=style
+                    draggable="false"
+                    alt=""
+                />
+            }.into_view());
+        }
+    }
+    tiles
+}
+
+// Карта навколо локації: базові тайли і радар опадів поверх.
+// Клік по карті — прогноз для цієї точки через on_pick(широта, довгота)
+#[component]
+pub fn MapPanel<P>(
+    location: GeoLocation,
+    zoom: ReadSignal<u8>,
+    set_zoom: WriteSignal<u8>,
+    on_pick: P,
+) -> impl IntoView
+where
+    P: Fn(f64, f64) + Copy + 'static,
+{
+    let base_tiles = WeatherClient::default().config().map_tiles_url.clone();
+    let (center, set_center) = create_signal((location.latitude, location.longitude));
+    // Зсув карти під час перетягування, пікселі. Тайли будуються від center і лише
+    // зсуваються transform-ом; center оновлюється, коли палець чи кнопку відпущено
+    let (pan, set_pan) = create_signal((0.0, 0.0));
+    let (show_radar, set_show_radar) = create_signal(true);
+    let (radar, set_radar) = create_signal(None::<Result<String, WeatherError>>);
+    // Початок перетягування: позиція вказівника
+    let drag = store_value(None::<(f64, f64)>);
+    let dragged = store_value(false);
+    let viewport = create_node_ref::<html::Div>();
+
+    // Перелік кадрів іде через кеш типового клієнта: повторне відкриття карти
+    // протягом кількох хвилин не звертається до RainViewer
+    spawn_local(async move {
+        set_radar.set(Some(radar_tiles().await));
+    });
+
+    // Миша, дотик і перо однаково: pointer-події з захопленням вказівника,
+    // щоб перетягування не обривалося за межами карти
+    let on_pointerdown = move |ev: ev::PointerEvent| {
+        if !ev.is_primary() {
+            return;
+        }
+        if let Some(el) = viewport.get_untracked() {
+            let _ = el.set_pointer_capture(ev.pointer_id());
+        }
+        drag.set_value(Some((f64::from(ev.client_x()), f64::from(ev.client_y()))));
+        dragged.set_value(false);
+    };
+
+    let on_pointermove = move |ev: ev::PointerEvent| {
+        let Some((start_x, start_y)) = drag.get_value() else {
+            return;
+        };
+        let dx = f64::from(ev.client_x()) - start_x;
+        let dy = f64::from(ev.client_y()) - start_y;
+        if dx.abs() + dy.abs() > DRAG_THRESHOLD {
+            dragged.set_value(true);
+        }
+        if dragged.get_value() {
+            set_pan.set((dx, dy));
+        }
+    };
+
+    let on_pointerup = move |ev: ev::PointerEvent| {
+        if drag.get_value().is_none() {
+            return;
+        }
+        drag.set_value(None);
+        let z = zoom.get_untracked();
+        let (lat, lon) = center.get_untracked();
+        let (cx, cy) = project(lat, lon, z);
+
+        if dragged.get_value() {
+            let (dx, dy) = pan.get_untracked();
+            batch(|| {
+                set_center.set(unproject(cx - dx, cy - dy, z));
+                set_pan.set((0.0, 0.0));
+            });
+            return;
+        }
+
+        let Some(el) = viewport.get_untracked() else {
+            return;
+        };
+        let x = cx + f64::from(ev.offset_x()) - f64::from(el.client_width()) / 2.0;
+        let y = cy + f64::from(ev.offset_y()) - f64::from(el.client_height()) / 2.0;
+        let (lat, lon) = unproject(x, y, z);
+        on_pick(lat, lon);
+    };
+
+    // Браузер забрав жест собі (прокрутка, системний жест) — повертаємо карту на місце
+    let on_pointercancel = move |_| {
+        drag.set_value(None);
+        set_pan.set((0.0, 0.0));
+    };
+
+    let zoom_by = move |delta: i8| {
+        set_zoom.update(|z| *z = z.saturating_add_signed(delta).clamp(MIN_ZOOM, MAX_ZOOM));
+    };
+
+    let marker_style = move || {
+        let z = zoom.get();
+        let (lat, lon) = center.get();
+        let (cx, cy) = project(lat, lon, z);
+        let (x, y) = project(location.latitude, location.longitude, z);
+        format!("left: calc(50% + {:.0}px); top: calc(50% + {:.0}px)", x - cx, y - cy)
+    };
+
+    view! {
+        <section class="m3-card p-6 md:p-10 mb-8">
+            <div class="flex flex-wrap justify-between items-center gap-4 mb-4">
+                <h3 class="text-xl font-medium text-blue-200">"Карта опадів"</h3>
+                <div class="flex items-center gap-3 text-sm">
+                    {move || match radar.get() {
+                        Some(Err(e)) => view! { <span class="text-red-300">{format!("Радар недоступний: {}", e)}</span> }.into_view(),
+                        None => view! { <span class="text-gray-500">"Завантаження радара..."</span> }.into_view(),
+                        Some(Ok(_)) => view! {
+                            <button
+                                class=move || if show_radar.get() {
+                                    "px-4 py-1.5 rounded-full bg-blue-200 text-[#0B1D33] font-medium"
+                                } else {
+                                    "px-4 py-1.5 rounded-full bg-[#2D2F31] text-gray-300 hover:bg-[#333537]"
+                                }
+                                on:click=move |_| set_show_radar.update(|show| *show = !*show)
+                            >
+                                "Радар опадів"
+                            </button>
+                        }.into_view(),
+                    }}
+                </div>
+            </div>
+
+            <div class="relative overflow-hidden rounded-2xl bg-[#2D2F31]" style=format!("height: {}px", MAP_HEIGHT)>
+                <div
+                    node_ref=viewport
+                    class="absolute inset-0 cursor-grab active:cursor-grabbing touch-none"
+                    on:pointerdown=on_pointerdown
+                    on:pointermove=on_pointermove
+                    on:pointerup=on_pointerup
+                    on:pointercancel=on_pointercancel
+                >
+                    <div
+                        class="absolute inset-0 pointer-events-none"
+                        style=move || {
+                            let (dx, dy) = pan.get();
+                            format!("transform: translate({:.0}px, {:.0}px)", dx, dy)
+                        }
+                    >
+                        {move || {
+                            let z = zoom.get();
+                            let (lat, lon) = center.get();
+                            let origin = project(lat, lon, z);
+                            let mut tiles = tile_layer(&base_tiles, z, z, origin, "");
+                            if let (true, Some(Ok(template))) = (show_radar.get(), radar.get()) {
+                                tiles.extend(tile_layer(&template, z.min(RADAR_MAX_ZOOM), z, origin, "opacity-60"));
+                            }
+                            tiles
+                        }}
+                        <div
+                            class="absolute w-4 h-4 -ml-2 -mt-2 rounded-full bg-blue-300 ring-4 ring-white/70"
+                            style=marker_style
+                        ></div>
+                    </div>
+                </div>
+
+                <div class="absolute top-3 right-3 flex flex-col rounded-xl overflow-hidden bg-[#2D2F31] text-white text-lg">
+                    <button
+                        class="w-9 h-9 hover:bg-[#333537] disabled:text-gray-600"
+                        disabled=move || zoom.get() >= MAX_ZOOM
+                        on:click=move |_| zoom_by(1)
+                    >
+                        "+"
+                    </button>
+                    <button
+                        class="w-9 h-9 hover:bg-[#333537] disabled:text-gray-600 border-t border-[#43474E]"
+                        disabled=move || zoom.get() <= MIN_ZOOM
+                        on:click=move |_| zoom_by(-1)
+                    >
+                        "−"
+                    </button>
+                </div>
+
+                <div class="absolute bottom-0 right-0 px-2 py-0.5 bg-black/50 text-[10px] text-gray-300">
+                    "© "
+                    <a href="https://www.openstreetmap.org/copyright" target="_blank" rel="noopener" class="underline">"OpenStreetMap"</a>
+                    ", радар: "
+                    <a href="https://www.rainviewer.com" target="_blank" rel="noopener" class="underline">"RainViewer"</a>
+                </div>
+            </div>
+
+            <p class="text-xs text-gray-500 mt-3">"Клацніть по карті, щоб отримати прогноз для цієї точки"</p>
+        </section>
+    }
+}
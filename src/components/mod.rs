@@ -1 +1,9 @@
+pub mod chart;
+pub mod compare;
+pub mod favorites;
+pub mod history;
+pub mod map;
+pub mod routing;
+pub mod storage;
+pub mod units;
 pub mod weather_app;
@@ -0,0 +1,170 @@
+use crate::api::weather::GeoLocation;
+
+// Стан, який відновлюється з адреси: ?name=Черкаси&lat=49.4445&lon=32.0597&day=2026-10-22
+#[derive(Debug, Clone, Default)]
+pub struct Route {
+    pub location: Option<GeoLocation>,
+    // Дата обраного дня; без неї — сьогодні
+    pub date: Option<String>,
+}
+
+impl Route {
+    pub fn parse(search: &str) -> Self {
+        let mut name = None;
+        let mut lat = None;
+        let mut lon = None;
+        let mut date = None;
+
+        for pair in search.trim_start_matches('?').split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = urlencoding::decode(&value.replace('+', " "))
+                .map(|v| v.into_owned())
+                .unwrap_or_default();
+            match key {
+                "name" => name = Some(value).filter(|v| !v.is_empty()),
+                "lat" => lat = value.parse::<f64>().ok(),
+                "lon" => lon = value.parse::<f64>().ok(),
+                "day" => date = Some(value).filter(|v| !v.is_empty()),
+                _ => {}
+            }
+        }
+
+        let location = match (lat, lon) {
+            (Some(lat), Some(lon)) => {
+                let mut location = GeoLocation::from_coords(lat, lon);
+                if let Some(name) = name {
+                    location.name = name;
+                }
+                Some(location)
+            }
+            _ => None,
+        };
+
+        Self { location, date }
+    }
+
+    pub fn to_query(&self) -> String {
+        let Some(location) = &self.location else {
+            return String::new();
+        };
+
+        let mut query = format!(
+            "?name={}&lat={:.4}&lon={:.4}",
+            urlencoding::encode(&location.name),
+            location.latitude,
+            location.longitude
+        );
+        if let Some(date) = &self.date {
+            query.push_str(&format!("&day={}", urlencoding::encode(date)));
+        }
+        query
+    }
+}
+
+pub fn current_route() -> Route {
+    web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .map(|search| Route::parse(&search))
+        .unwrap_or_default()
+}
+
+// Додає запис в історію браузера, якщо адреса змінилася
+pub fn push_route(route: &Route) {
+    set_route(route, false);
+}
+
+// Нормалізує адресу, з якої щойно відновлено стан (напр. day=<сьогодні> -> без day),
+// не додаючи запису: інакше «Назад» повертав би на ту саму сторінку
+pub fn replace_route(route: &Route) {
+    set_route(route, true);
+}
+
+fn set_route(route: &Route, replace: bool) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let query = route.to_query();
+    if query.is_empty() || window.location().search().ok().as_deref() == Some(query.as_str()) {
+        return;
+    }
+
+    if let Ok(history) = window.history() {
+        let _ = if replace {
+            history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&query))
+        } else {
+            history.push_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&query))
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(name: &str, lat: f64, lon: f64, date: Option<&str>) -> Route {
+        let mut location = GeoLocation::from_coords(lat, lon);
+        location.name = name.to_string();
+        Route {
+            location: Some(location),
+            date: date.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn round_trip_keeps_name_coords_and_day() {
+        let query = route("Біла Церква", 49.7957, 30.1311, Some("2026-10-22")).to_query();
+        let parsed = Route::parse(&query);
+
+        let location = parsed.location.expect("локація з адреси");
+        assert_eq!(location.name, "Біла Церква");
+        assert_eq!(location.latitude, 49.7957);
+        assert_eq!(location.longitude, 30.1311);
+        assert_eq!(parsed.date.as_deref(), Some("2026-10-22"));
+    }
+
+    // push_route порівнює to_query() з location.search() посимвольно
+    #[test]
+    fn reparsed_query_is_byte_for_byte_stable() {
+        for query in [
+            route("Черкаси", 49.4445, 32.0597, None).to_query(),
+            route("Біла Церква", 49.7957, 30.1311, Some("2026-10-22")).to_query(),
+            route("49.4444, 32.0600", 49.4444, 32.06, None).to_query(),
+        ] {
+            assert_eq!(Route::parse(&query).to_query(), query);
+        }
+    }
+
+    #[test]
+    fn coords_are_rounded_to_four_decimals() {
+        let query = route("Черкаси", 49.444_549, -32.059_71, None).to_query();
+
+        assert!(query.ends_with("&lat=49.4445&lon=-32.0597"), "{}", query);
+    }
+
+    #[test]
+    fn plus_and_percent_twenty_both_decode_to_space() {
+        for search in ["?name=Bila+Tserkva&lat=49.8&lon=30.1", "?name=Bila%20Tserkva&lat=49.8&lon=30.1"] {
+            let location = Route::parse(search).location.expect("локація з адреси");
+            assert_eq!(location.name, "Bila Tserkva");
+        }
+        // Назад кодуємо лише як %20
+        let query = route("Bila Tserkva", 49.8, 30.1, None).to_query();
+        assert!(query.starts_with("?name=Bila%20Tserkva&"), "{}", query);
+    }
+
+    #[test]
+    fn missing_coords_give_no_location() {
+        for search in ["?name=Черкаси&lat=49.44", "?name=Черкаси&lon=32.06", "?lat=abc&lon=32.06", ""] {
+            assert!(Route::parse(search).location.is_none(), "{}", search);
+        }
+        assert_eq!(Route::default().to_query(), "");
+    }
+
+    #[test]
+    fn name_falls_back_to_coords_and_empty_day_is_none() {
+        let parsed = Route::parse("?name=&lat=49.4445&lon=32.0597&day=");
+
+        assert_eq!(parsed.location.map(|l| l.name).as_deref(), Some("49.4445, 32.0597"));
+        assert_eq!(parsed.date, None);
+    }
+}
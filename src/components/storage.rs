@@ -0,0 +1,19 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+// Обгортка над localStorage: значення зберігаються як JSON.
+// Недоступне сховище (приватний режим, квота) не є помилкою для UI
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let raw = local_storage()?.get_item(key).ok().flatten()?;
+    serde_json::from_str(&raw).ok()
+}
+
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    let (Some(storage), Ok(raw)) = (local_storage(), serde_json::to_string(value)) else {
+        return;
+    };
+    let _ = storage.set_item(key, &raw);
+}
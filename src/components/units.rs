@@ -0,0 +1,86 @@
+use leptos::*;
+
+use crate::api::units::{PressureUnit, TemperatureUnit, Units, WindUnit};
+use crate::api::weather::{ForecastRange, HourlyResolution};
+use crate::components::storage;
+
+const UNITS_KEY: &str = "weather.units";
+const RESOLUTION_KEY: &str = "weather.resolution";
+const RANGE_KEY: &str = "weather.range";
+
+pub fn load_units() -> Units {
+    storage::load(UNITS_KEY).unwrap_or_default()
+}
+
+pub fn save_units(units: &Units) {
+    storage::save(UNITS_KEY, units);
+}
+
+pub fn load_resolution() -> HourlyResolution {
+    storage::load(RESOLUTION_KEY).unwrap_or_default()
+}
+
+pub fn save_resolution(resolution: HourlyResolution) {
+    storage::save(RESOLUTION_KEY, &resolution);
+}
+
+pub fn load_range() -> ForecastRange {
+    storage::load(RANGE_KEY).unwrap_or_default()
+}
+
+pub fn save_range(range: ForecastRange) {
+    storage::save(RANGE_KEY, &range);
+}
+
+#[component]
+pub fn UnitsPicker(units: ReadSignal<Units>, set_units: WriteSignal<Units>) -> impl IntoView {
+    view! {
+        <div class="flex gap-2 text-sm">
+            <UnitSelect
+                title="Температура"
+                options=TemperatureUnit::ALL.iter().map(|u| u.label()).collect()
+                selected=Signal::derive(move || TemperatureUnit::ALL.iter().position(|u| *u == units.get().temperature).unwrap_or(0))
+                on_select=move |idx| set_units.update(|u| u.temperature = TemperatureUnit::ALL[idx])
+            />
+            <UnitSelect
+                title="Вітер"
+                options=WindUnit::ALL.iter().map(|u| u.label()).collect()
+                selected=Signal::derive(move || WindUnit::ALL.iter().position(|u| *u == units.get().wind).unwrap_or(0))
+                on_select=move |idx| set_units.update(|u| u.wind = WindUnit::ALL[idx])
+            />
+            <UnitSelect
+                title="Тиск"
+                options=PressureUnit::ALL.iter().map(|u| u.label()).collect()
+                selected=Signal::derive(move || PressureUnit::ALL.iter().position(|u| *u == units.get().pressure).unwrap_or(0))
+                on_select=move |idx| set_units.update(|u| u.pressure = PressureUnit::ALL[idx])
+            />
+        </div>
+    }
+}
+
+#[component]
+fn UnitSelect<F>(
+    title: &'static str,
+    options: Vec<&'static str>,
+    selected: Signal<usize>,
+    on_select: F,
+) -> impl IntoView
+where
+    F: Fn(usize) + 'static,
+{
+    view! {
+        <select
+            class="m3-chip text-gray-300 outline-none cursor-pointer"
+            title=title
+            on:change=move |ev| {
+                if let Ok(idx) = event_target_value(&ev).parse() {
+                    on_select(idx);
+                }
+            }
+        >
+            {options.into_iter().enumerate().map(|(idx, label)| view! {
+                <option value=idx.to_string() selected=move || selected.get() == idx>{label}</option>
+            }).collect::<Vec<_>>()}
+        </select>
+    }
+}
@@ -1,58 +1,414 @@
+use std::time::Duration;
+
 use leptos::*;
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use crate::api::error::WeatherError;
+use crate::api::http::AbortHandle;
+use crate::api::time::{now_unix, LocalTime};
+use crate::api::units::Units;
 use crate::api::weather::*;
+use crate::components::chart::HourlyChart;
+use crate::components::compare::CompareDashboard;
+use crate::components::favorites::{load_favorites, save_favorites, toggle_favorite, FavoriteChips};
+use crate::components::history::{load_history, remember, save_history};
+use crate::components::map::{MapPanel, DEFAULT_ZOOM};
+use crate::components::routing::{current_route, push_route, replace_route, Route};
+use crate::components::units::{
+    load_range, load_resolution, load_units, save_range, save_resolution, save_units, UnitsPicker,
+};
 use wasm_bindgen::prelude::*;
 
 // Інтеграція з Lucide icons
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = lucide)]
-    fn createIcons();
+    pub(crate) fn createIcons();
 }
 
-// Функція для отримання поточної години
-#[wasm_bindgen(inline_js = "export function get_current_hour() { return new Date().getHours(); }")]
-extern "C" {
-    fn get_current_hour() -> i32;
-}
+// Затримка перед запитом підказок під час набору
+const SUGGEST_DEBOUNCE: Duration = Duration::from_millis(300);
+// Мінімальна довжина запиту для підказок
+const SUGGEST_MIN_CHARS: usize = 2;
+// Затримка перед закриттям історії чи підказок, коли поле втрачає фокус: інакше
+// клік по запису не встигне спрацювати
+const BLUR_CLOSE_DELAY: Duration = Duration::from_millis(150);
+const HISTORY_TITLE: &str = "Нещодавні пошуки";
+
+// Як часто оновлюємо годинник локації та підсвічування поточної години
+const CLOCK_TICK: Duration = Duration::from_secs(30);
 
 #[component]
 pub fn WeatherApp() -> impl IntoView {
     let (city, set_city) = create_signal(String::new());
     let (weather_data, set_weather_data) = create_signal(None::<WeatherData>);
     let (loading, set_loading) = create_signal(false);
-    let (error, set_error) = create_signal(None::<String>);
+    // Замість однієї локації — порівняння всіх обраних
+    let (comparing, set_comparing) = create_signal(false);
+    let (error, set_error) = create_signal(None::<WeatherError>);
     let (selected_day, set_selected_day) = create_signal(0);
+    let (now, set_now) = create_signal(now_unix());
+    // Кандидати геокодингу, з яких користувач обирає потрібний населений пункт
+    let (candidates, set_candidates) = create_signal(None::<Vec<GeoLocation>>);
+    let (highlighted, set_highlighted) = create_signal(None::<usize>);
+    let (picker_title, set_picker_title) = create_signal("");
+    let (favorites, set_favorites) = create_signal(load_favorites());
+    let (history, set_history) = create_signal(load_history());
+    let (units, set_units) = create_signal(load_units());
+    let (resolution, set_resolution) = create_signal(load_resolution());
+    let (range, set_range) = create_signal(load_range());
+    // Масштаб карти переживає перезавантаження погоди після кліку по карті
+    let (map_zoom, set_map_zoom) = create_signal(DEFAULT_ZOOM);
+    // Номер останнього запиту підказок: відповіді на старіші запити відкидаються
+    let suggest_generation = store_value(0u64);
+    // Те саме для погоди: повільна відповідь не перекриває новіший вибір локації
+    let load_generation = store_value(0u64);
+    // Переривання запиту підказок, що ще виконується
+    let suggest_abort = store_value(None::<AbortHandle>);
+    let blur_timer = store_value(None::<TimeoutHandle>);
+    let suggest_timer = store_value(None::<TimeoutHandle>);
+    // Дата з адреси, яку треба обрати, щойно прийде прогноз
+    let pending_date = store_value(None::<String>);
+    // Завантаження, чий стан узято з адреси: її лише нормалізуємо, без нового запису в історії
+    let route_load = store_value(None::<u64>);
+    // Останній запит погоди: локація, тривалість і день — для кнопки «Повторити»
+    let last_request = store_value(None::<(GeoLocation, ForecastRange, Option<String>)>);
+
+    let open_candidates = move |title: &'static str, list: Vec<GeoLocation>| {
+        set_picker_title.set(title);
+        set_highlighted.set(None);
+        set_candidates.set(Some(list));
+        request_animation_frame(move || {
+            createIcons();
+        });
+    };
+
+    let close_candidates = move || {
+        set_candidates.set(None);
+        set_highlighted.set(None);
+    };
+
+    // Порожнє поле у фокусі — пропонуємо нещодавні пошуки
+    let show_history = move || {
+        let recent = history.get_untracked();
+        if city.get_untracked().trim().is_empty() && !recent.is_empty() {
+            open_candidates(HISTORY_TITLE, recent);
+        }
+    };
+
+    // Скасовує відкладений і перериває вже надісланий запит підказок
+    let cancel_suggestions = move || {
+        if let Some(handle) = suggest_timer.get_value() {
+            handle.clear();
+        }
+        suggest_timer.set_value(None);
+        if let Some(abort) = suggest_abort.get_value() {
+            abort.abort();
+        }
+        suggest_abort.set_value(None);
+        // Відповідь могла прийти раніше за abort() — її теж відкидаємо
+        suggest_generation.update_value(|g| *g += 1);
+    };
+
+    let cancel_blur_close = move || {
+        if let Some(handle) = blur_timer.get_value() {
+            handle.clear();
+        }
+        blur_timer.set_value(None);
+    };
+
+    // Поле втратило фокус — ховаємо історію чи підказки і скасовуємо ще не
+    // отримані підказки. Кнопка «Пошук» теж забирає фокус, тому do_fetch
+    // скасовує це закриття, і список збігів лишається відкритим
+    let close_picker_later = move || {
+        cancel_blur_close();
+        let handle = set_timeout_with_handle(
+            move || {
+                cancel_suggestions();
+                close_candidates();
+            },
+            BLUR_CLOSE_DELAY,
+        )
+        .ok();
+        blur_timer.set_value(handle);
+    };
+
+    let fetch_suggestions = move |query: String| {
+        let generation = suggest_generation.get_value();
+        let abort = AbortHandle::new();
+        suggest_abort.set_value(Some(abort.clone()));
+        spawn_local(async move {
+            let result = suggest_locations(&query, &abort).await;
+            if suggest_generation.get_value() != generation {
+                return;
+            }
+            // Помилки підказок не показуємо: користувач ще набирає запит
+            match result {
+                Ok(found) => open_candidates("Оберіть населений пункт", found),
+                Err(_) => close_candidates(),
+            }
+        });
+    };
+
+    let on_query_input = move |value: String| {
+        set_city.set(value.clone());
+        cancel_suggestions();
+
+        let query = value.trim().to_string();
+        if query.chars().count() < SUGGEST_MIN_CHARS {
+            close_candidates();
+            show_history();
+            return;
+        }
+
+        let handle = set_timeout_with_handle(move || fetch_suggestions(query), SUGGEST_DEBOUNCE).ok();
+        suggest_timer.set_value(handle);
+    };
+
+    // Новий запит погоди робить застарілими всі попередні
+    let next_load = move || {
+        load_generation.update_value(|g| *g += 1);
+        load_generation.get_value()
+    };
+    let is_current_load = move |generation: u64| load_generation.get_value() == generation;
+
+    let show_weather = move |generation: u64, result: Result<WeatherData, WeatherError>| {
+        if !is_current_load(generation) {
+            return;
+        }
+        match result {
+            Ok(data) => {
+                set_history.update(|list| remember(list, data.location()));
+                let day = pending_date
+                    .get_value()
+                    .and_then(|date| day_index(&data, &date))
+                    .unwrap_or(0);
+                pending_date.set_value(None);
+                // Разом, щоб адреса не отримала проміжний стан (новий день для старого міста)
+                batch(|| {
+                    set_selected_day.set(day);
+                    set_weather_data.set(Some(data));
+                    set_comparing.set(false);
+                });
+                set_error.set(None);
+                
+                // Ініціалізуємо Lucide icons після оновлення DOM
+                request_animation_frame(move || {
+                    createIcons();
+                });
+            }
+            Err(e) => {
+                set_error.set(Some(e));
+                set_weather_data.set(None);
+                set_comparing.set(false);
+            }
+        }
+        set_loading.set(false);
+    };
+
+    // Запит погоди, який запам'ятовується для повтору
+    let fetch_weather = move |location: GeoLocation| {
+        let range = range.get_untracked();
+        last_request.set_value(Some((location.clone(), range, pending_date.get_value())));
+        get_weather_for_range(location, range)
+    };
+
+    let load_location = move |location: GeoLocation| {
+        cancel_suggestions();
+        close_candidates();
+        set_city.set(location.name.clone());
+        set_loading.set(true);
+        set_error.set(None);
+        let generation = next_load();
+
+        spawn_local(async move {
+            show_weather(generation, fetch_weather(location).await);
+        });
+    };
+
+    // Інша тривалість прогнозу — перезавантажуємо ту саму локацію, зберігаючи обраний день
+    let change_range = move |new_range: ForecastRange| {
+        if range.get_untracked() == new_range {
+            return;
+        }
+        set_range.set(new_range);
+        if let Some(data) = weather_data.get_untracked() {
+            let date = data
+                .forecast
+                .as_ref()
+                .and_then(|days| days.get(selected_day.get_untracked()))
+                .map(|day| day.date.clone());
+            pending_date.set_value(date);
+            load_location(data.location());
+        }
+    };
+
+    // Погода за довільними координатами; назву підбирає зворотний геокодинг
+    let load_coords = move |lat: f64, lon: f64| {
+        cancel_suggestions();
+        close_candidates();
+        set_loading.set(true);
+        set_error.set(None);
+        last_request.set_value(None);
+        let generation = next_load();
+
+        spawn_local(async move {
+            let location = locate(lat, lon).await;
+            if !is_current_load(generation) {
+                return;
+            }
+            set_city.set(location.name.clone());
+            show_weather(generation, fetch_weather(location).await);
+        });
+    };
+
+    // Точка з карти: прогноз за координатами з підписом "lat, lon", без зворотного
+    // геокодингу — інакше кожен клік був би ще й запитом до BigDataCloud
+    let pick_on_map = move |lat: f64, lon: f64| load_location(GeoLocation::from_coords(lat, lon));
+
+    // Погода за координатами браузера
+    let locate_me = move || {
+        cancel_suggestions();
+        close_candidates();
+        set_loading.set(true);
+        set_error.set(None);
+        let generation = next_load();
+
+        current_position(
+            move |lat, lon| {
+                if is_current_load(generation) {
+                    load_coords(lat, lon);
+                }
+            },
+            move |message| show_weather(generation, Err(WeatherError::LocationUnavailable { message })),
+        );
+    };
 
     let do_fetch = move || {
+        cancel_blur_close();
         let city_value = city.get_untracked();
-        if city_value.is_empty() {
-            set_error.set(Some("Будь ласка, введіть назву міста".to_string()));
+        if city_value.trim().is_empty() {
+            set_error.set(Some(WeatherError::EmptyQuery));
             return;
         }
 
+        cancel_suggestions();
+        close_candidates();
         set_loading.set(true);
         set_error.set(None);
+        last_request.set_value(None);
+        let generation = next_load();
 
         spawn_local(async move {
-            match get_weather(&city_value).await {
-                Ok(data) => {
-                    set_weather_data.set(Some(data));
-                    set_error.set(None);
-                    
-                    // Ініціалізуємо Lucide icons після оновлення DOM
-                    request_animation_frame(move || {
-                        createIcons();
-                    });
+            let result = search_locations(&city_value).await;
+            if !is_current_load(generation) {
+                return;
+            }
+            match result {
+                // Єдиний збіг — одразу завантажуємо погоду
+                Ok(mut found) if found.len() == 1 => {
+                    show_weather(generation, fetch_weather(found.swap_remove(0)).await);
                 }
-                Err(e) => {
-                    set_error.set(Some(format!("Помилка: {}", e)));
-                    set_weather_data.set(None);
+                Ok(found) => {
+                    open_candidates("Оберіть населений пункт", found);
+                    set_loading.set(false);
                 }
+                Err(e) => show_weather(generation, Err(e)),
             }
-            set_loading.set(false);
         });
     };
 
+    // Повтор того самого запиту: локацію з обраного, карти чи адреси не геокодуємо
+    // знову за текстом у полі пошуку. Без завантаженої локації — повторюємо пошук
+    let retry = move || match last_request.get_value() {
+        Some((location, last_range, date)) => {
+            set_range.set(last_range);
+            pending_date.set_value(date);
+            load_location(location);
+        }
+        None => do_fetch(),
+    };
+
+    // Стан з адреси при першому завантаженні
+    let initial = current_route();
+    if let Some(location) = initial.location {
+        pending_date.set_value(initial.date);
+        load_location(location);
+        route_load.set_value(Some(load_generation.get_value()));
+    }
+
+    // Кнопки «назад»/«вперед» браузера
+    window_event_listener(ev::popstate, move |_| {
+        let route = current_route();
+        let Some(location) = route.location else {
+            // Запит, що ще виконується, не повинен повернути погоду на порожню сторінку
+            next_load();
+            set_loading.set(false);
+            set_weather_data.set(None);
+            return;
+        };
+
+        let same_place = weather_data.with_untracked(|d| {
+            d.as_ref().is_some_and(|d| d.location().same_place(&location))
+        });
+        if same_place {
+            let day = route
+                .date
+                .and_then(|date| weather_data.with_untracked(|d| d.as_ref().and_then(|d| day_index(d, &date))))
+                .unwrap_or(0);
+            route_load.set_value(Some(next_load()));
+            set_loading.set(false);
+            set_selected_day.set(day);
+        } else {
+            pending_date.set_value(route.date);
+            load_location(location);
+            route_load.set_value(Some(load_generation.get_value()));
+        }
+    });
+
+    // Оновлюємо адресу після пошуку чи зміни дня
+    create_effect(move |_| {
+        let day = selected_day.get();
+        weather_data.with(|data| {
+            if let Some(data) = data {
+                let date = (day > 0)
+                    .then(|| data.forecast.as_ref().and_then(|f| f.get(day)).map(|d| d.date.clone()))
+                    .flatten();
+                let route = Route {
+                    location: Some(data.location()),
+                    date,
+                };
+                if route_load.get_value() == Some(load_generation.get_value()) {
+                    route_load.set_value(None);
+                    replace_route(&route);
+                } else {
+                    push_route(&route);
+                }
+            }
+        });
+    });
+
+    // Зберігаємо обране та історію після кожної зміни
+    create_effect(move |_| {
+        favorites.with(|list| save_favorites(list));
+    });
+    create_effect(move |_| {
+        history.with(|list| save_history(list));
+    });
+    create_effect(move |_| {
+        save_units(&units.get());
+    });
+    create_effect(move |_| {
+        save_resolution(resolution.get());
+    });
+    create_effect(move |_| {
+        save_range(range.get());
+    });
+
+    // Годинник для місцевого часу локації
+    if let Ok(handle) = set_interval_with_handle(move || set_now.set(now_unix()), CLOCK_TICK) {
+        on_cleanup(move || handle.clear());
+    }
+
     // Ініціалізуємо ікони при першому рендері
     create_effect(move |_| {
         request_animation_frame(move || {
@@ -77,15 +433,52 @@ pub fn WeatherApp() -> impl IntoView {
                             type="text" 
                             placeholder="Назва населеного пункту..." 
                             class="bg-transparent border-none outline-none w-full text-white placeholder-gray-500"
-                            on:input=move |ev| {
-                                set_city.set(event_target_value(&ev));
+                            prop:value=city
+                            on:input=move |ev| on_query_input(event_target_value(&ev))
+                            on:focus=move |_| {
+                                cancel_blur_close();
+                                if candidates.with_untracked(Option::is_none) {
+                                    show_history();
+                                }
                             }
-                            on:keypress=move |ev: web_sys::KeyboardEvent| {
-                                if ev.key() == "Enter" {
-                                    do_fetch();
+                            on:blur=move |_| close_picker_later()
+                            on:keydown=move |ev: web_sys::KeyboardEvent| {
+                                let count = candidates.with_untracked(|c| c.as_ref().map_or(0, Vec::len));
+                                match ev.key().as_str() {
+                                    "ArrowDown" if count > 0 => {
+                                        ev.prevent_default();
+                                        set_highlighted.update(|h| {
+                                            *h = Some(h.map_or(0, |i| (i + 1).min(count - 1)));
+                                        });
+                                    }
+                                    "ArrowUp" if count > 0 => {
+                                        ev.prevent_default();
+                                        set_highlighted.update(|h| *h = h.and_then(|i| i.checked_sub(1)));
+                                    }
+                                    "Enter" => {
+                                        let picked = highlighted.get_untracked().and_then(|i| {
+                                            candidates.with_untracked(|c| c.as_ref().and_then(|c| c.get(i).cloned()))
+                                        });
+                                        match picked {
+                                            Some(location) => load_location(location),
+                                            None => do_fetch(),
+                                        }
+                                    }
+                                    "Escape" => {
+                                        cancel_suggestions();
+                                        close_candidates();
+                                    }
+                                    _ => {}
                                 }
                             }
                         />
+                        <button
+                            class="text-gray-400 hover:text-blue-300 transition-colors"
+                            title="Моє місцезнаходження"
+                            on:click=move |_| locate_me()
+                        >
+                            <i data-lucide="locate-fixed" class="w-5 h-5"></i>
+                        </button>
                         <button 
                             class="bg-blue-400 hover:bg-blue-300 text-black font-medium px-6 py-2 rounded-full transition-colors"
                             on:click=move |_| do_fetch()
@@ -93,25 +486,28 @@ pub fn WeatherApp() -> impl IntoView {
                             "Пошук"
                         </button>
                     </div>
-                    <div class="flex gap-4 mt-2 px-2 text-sm text-gray-400">
-                        <span 
-                            class="hover:text-blue-300 cursor-pointer"
-                            on:click=move |_| { set_city.set("Оржиця".to_string()); do_fetch(); }
-                        >"Оржиця"</span>
-                        <span 
-                            class="hover:text-blue-300 cursor-pointer"
-                            on:click=move |_| { set_city.set("Черкаси".to_string()); do_fetch(); }
-                        >"Черкаси"</span>
-                    </div>
+                    {move || candidates.get().map(|found| view! {
+                        <LocationPicker
+                            title=picker_title.get_untracked()
+                            candidates=found
+                            query=city
+                            highlighted=highlighted
+                            on_pick=load_location
+                            on_close=close_candidates
+                        />
+                    })}
+                    <FavoriteChips
+                        favorites=favorites
+                        set_favorites=set_favorites
+                        on_pick=load_location
+                        on_compare=move || set_comparing.update(|c| *c = !*c)
+                    />
                 </div>
+                <UnitsPicker units=units set_units=set_units/>
             </header>
             
             {move || error.get().map(|err| {
-                view! {
-                    <div class="bg-red-900 bg-opacity-20 border border-red-700 text-red-300 px-6 py-4 rounded-[28px] mb-8">
-                        {err}
-                    </div>
-                }
+                view! { <ErrorBanner error=err on_retry=retry/> }
             })}
 
             {move || {
@@ -122,26 +518,62 @@ pub fn WeatherApp() -> impl IntoView {
                             <p class="text-gray-400">"Завантаження..."</p>
                         </div>
                     }.into_view()
+                } else if comparing.get() {
+                    view! {
+                        <CompareDashboard
+                            locations=favorites
+                            units=units
+                            on_pick=load_location
+                            on_close=move || set_comparing.set(false)
+                        />
+                    }.into_view()
                 } else if let Some(data) = weather_data.get() {
+                    let location = data.location();
+                    let is_favorite = {
+                        let location = location.clone();
+                        Signal::derive(move || favorites.with(|list| list.iter().any(|f| f.same_place(&location))))
+                    };
                     view! {
                         <>
+                            {data.forecast_error.clone().map(|err| {
+                                view! { <ErrorBanner error=err on_retry=retry/> }
+                            })}
                             <MainSection 
                                 city=data.name.clone() 
-                                region=data.country.clone()
-                                selected_day=selected_day
-                                set_selected_day=set_selected_day
+                                region=data.region()
+                                timezone=data.timezone.clone()
+                                local_time={
+                                    let offset = data.utc_offset_seconds;
+                                    Signal::derive(move || LocalTime::from_unix(now.get(), offset))
+                                }
+                                is_favorite=is_favorite
+                                on_toggle_favorite=move || set_favorites.update(|list| toggle_favorite(list, location.clone()))
+                                range=range
+                                on_range=change_range
                             />
+                            <CurrentConditions data=data.clone() units=units/>
                             <WeeklyStrip 
                                 selected_day=selected_day
                                 set_selected_day=set_selected_day
+                                units=units
                                 forecast=data.forecast.clone()
                             />
                             <DetailedCard 
                                 data=data.clone()
                                 selected_day=selected_day
+                                units=units
+                                now=now
+                                resolution=resolution
+                                set_resolution=set_resolution
                                 forecast=data.forecast.clone()
                             />
-                            <DescriptionsInfo city=data.name.clone()/>
+                            <MapPanel
+                                location=data.location()
+                                zoom=map_zoom
+                                set_zoom=set_map_zoom
+                                on_pick=pick_on_map
+                            />
+                            <DescriptionsInfo/>
                         </>
                     }.into_view()
                 } else {
@@ -157,40 +589,177 @@ pub fn WeatherApp() -> impl IntoView {
 }
 
 #[component]
-fn MainSection(
+fn LocationPicker<P, C>(
+    title: &'static str,
+    candidates: Vec<GeoLocation>,
+    query: ReadSignal<String>,
+    highlighted: ReadSignal<Option<usize>>,
+    on_pick: P,
+    on_close: C,
+) -> impl IntoView
+where
+    P: Fn(GeoLocation) + Copy + 'static,
+    C: Fn() + Copy + 'static,
+{
+    view! {
+        // Клік усередині списку не забирає фокус у поля пошуку
+        <div
+            class="absolute left-0 right-0 top-full mt-2 z-20 m3-surface border border-[#43474E] rounded-[28px] py-2 shadow-lg"
+            on:mousedown=|ev| ev.prevent_default()
+        >
+            <div class="flex justify-between items-center px-5 py-2 text-xs text-gray-500 uppercase tracking-widest">
+                <span>{title}</span>
+                <button class="hover:text-white" on:click=move |_| on_close()>
+                    <i data-lucide="x" class="w-4 h-4"></i>
+                </button>
+            </div>
+            <ul class="max-h-80 overflow-y-auto no-scrollbar">
+                {candidates.into_iter().enumerate().map(|(idx, location)| {
+                    let name = location.name.clone();
+                    let region = location.region();
+                    let population = location.population.filter(|p| *p > 0);
+                    view! {
+                        <li
+                            class="px-5 py-3 cursor-pointer hover:bg-[#2D2F31] flex justify-between items-center gap-4"
+                            class=("bg-[#2D2F31]", move || highlighted.get() == Some(idx))
+                            on:click=move |_| on_pick(location.clone())
+                        >
+                            <div>
+                                <p class="text-white">
+                                    {move || {
+                                        let (matched, rest) = split_prefix(&name, &query.get());
+                                        view! {
+                                            <span class="font-bold text-blue-200">{matched}</span>
+                                            {rest}
+                                        }
+                                    }}
+                                </p>
+                                <p class="text-sm text-gray-400">{region}</p>
+                            </div>
+                            {population.map(|p| view! {
+                                <span class="text-xs text-gray-500 whitespace-nowrap">{format!("{} меш.", p)}</span>
+                            })}
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+        </div>
+    }
+}
+
+// Індекс дня прогнозу за датою "YYYY-MM-DD"
+fn day_index(data: &WeatherData, date: &str) -> Option<usize> {
+    data.forecast.as_ref()?.iter().position(|d| d.date == date)
+}
+
+// Ділить назву на частину, що збігається з початком запиту (без урахування регістру), і решту
+fn split_prefix(name: &str, query: &str) -> (String, String) {
+    let query = query.trim().to_lowercase();
+    let mut query_chars = query.chars();
+    let mut split_at = 0;
+
+    for (idx, ch) in name.char_indices() {
+        match query_chars.next() {
+            Some(q) if ch.to_lowercase().eq(q.to_lowercase()) => split_at = idx + ch.len_utf8(),
+            Some(_) => return (String::new(), name.to_string()),
+            None => break,
+        }
+    }
+
+    if query_chars.next().is_some() {
+        return (String::new(), name.to_string());
+    }
+
+    (name[..split_at].to_string(), name[split_at..].to_string())
+}
+
+#[component]
+fn ErrorBanner<F>(error: WeatherError, on_retry: F) -> impl IntoView
+where
+    F: Fn() + Copy + 'static,
+{
+    // Часткова помилка прогнозу — попередження, а не повна відмова
+    let is_warning = matches!(error, WeatherError::PartialForecast(_));
+    let hint = match &error {
+        WeatherError::CityNotFound { .. } => Some("Перевірте написання або спробуйте найближче місто"),
+        WeatherError::PartialForecast(_) => Some("Показано лише поточну погоду"),
+        WeatherError::Decode { .. } => Some("Сервіс повернув неочікувані дані"),
+        _ => None,
+    };
+    let retryable = error.is_retryable();
+    let palette = if is_warning {
+        "bg-yellow-900 border-yellow-700 text-yellow-200"
+    } else {
+        "bg-red-900 border-red-700 text-red-300"
+    };
+
+    view! {
+        <div class={format!("{} bg-opacity-20 border px-6 py-4 rounded-[28px] mb-8 flex flex-col md:flex-row md:items-center justify-between gap-4", palette)}>
+            <div>
+                <p>{error.to_string()}</p>
+                {hint.map(|h| view! { <p class="text-sm opacity-70 mt-1">{h}</p> })}
+            </div>
+            {retryable.then(|| view! {
+                <button
+                    class="bg-[#2D2F31] hover:bg-[#333537] text-white font-medium px-5 py-2 rounded-full transition-colors"
+                    on:click=move |_| on_retry()
+                >
+                    "Повторити"
+                </button>
+            })}
+        </div>
+    }
+}
+
+#[component]
+fn MainSection<F, R>(
     city: String,
     region: String,
-    selected_day: ReadSignal<usize>,
-    set_selected_day: WriteSignal<usize>,
-) -> impl IntoView {
+    timezone: String,
+    local_time: Signal<LocalTime>,
+    is_favorite: Signal<bool>,
+    on_toggle_favorite: F,
+    range: ReadSignal<ForecastRange>,
+    on_range: R,
+) -> impl IntoView
+where
+    F: Fn() + 'static,
+    R: Fn(ForecastRange) + Copy + 'static,
+{
     view! {
         <section class="mb-8 flex flex-col md:flex-row justify-between items-end gap-6">
             <div>
-                <h2 class="text-4xl font-medium mb-1">{format!("Погода у {}", city)}</h2>
+                <h2 class="text-4xl font-medium mb-1 flex items-center gap-3">
+                    {format!("Погода у {}", city)}
+                    <button
+                        class="text-3xl transition-colors hover:text-yellow-300"
+                        class:text-yellow-400=move || is_favorite.get()
+                        class:text-gray-500=move || !is_favorite.get()
+                        title=move || if is_favorite.get() { "Прибрати з обраного" } else { "Додати до обраного" }
+                        on:click=move |_| on_toggle_favorite()
+                    >
+                        {move || if is_favorite.get() { "★" } else { "☆" }}
+                    </button>
+                    <span class="text-base font-normal text-gray-400" title=timezone>
+                        {move || format!("місцевий час {}", local_time.get().clock())}
+                    </span>
+                </h2>
                 <p class="text-gray-400">{region}</p>
             </div>
-            /*<div class="bg-[#2D2F31] p-1 rounded-full flex">
-                <button 
-                    class="px-6 py-2 rounded-full font-medium text-sm transition-colors"
-                    class:bg-blue-200=move || selected_day.get() == 0
-                    class:text-black=move || selected_day.get() == 0
-                    class:text-gray-400=move || selected_day.get() != 0
-                    class:hover:text-white=move || selected_day.get() != 0
-                    on:click=move |_| set_selected_day.set(0)
-                >
-                    "Тиждень"
-                </button>
-                <button 
-                    class="px-6 py-2 rounded-full font-medium text-sm transition-colors"
-                    class:bg-blue-200=move || selected_day.get() == 1
-                    class:text-black=move || selected_day.get() == 1
-                    class:text-gray-400=move || selected_day.get() != 1
-                    class:hover:text-white=move || selected_day.get() != 1
-                    on:click=move |_| set_selected_day.set(1)
-                >
-                    "10 днів"
-                </button>
-            </div>*/
+            <div class="bg-[#2D2F31] p-1 rounded-full flex">
+                {ForecastRange::ALL.into_iter().map(|r| view! {
+                    <button 
+                        class="px-6 py-2 rounded-full font-medium text-sm transition-colors"
+                        class:bg-blue-200=move || range.get() == r
+                        class:text-black=move || range.get() == r
+                        class:text-gray-400=move || range.get() != r
+                        class:hover:text-white=move || range.get() != r
+                        on:click=move |_| on_range(r)
+                    >
+                        {r.label()}
+                    </button>
+                }).collect::<Vec<_>>()}
+            </div>
         </section>
     }
 }
@@ -199,17 +768,25 @@ fn MainSection(
 fn WeeklyStrip(
     selected_day: ReadSignal<usize>,
     set_selected_day: WriteSignal<usize>,
+    units: ReadSignal<Units>,
     forecast: Option<Vec<crate::api::weather::DayForecast>>,
 ) -> impl IntoView {
+    // Тиждень вміщається на картці; довший прогноз гортається зі смугою прокрутки
+    let long_range = forecast.as_ref().is_some_and(|days| days.len() > ForecastRange::Week.days() as usize);
+    // Спільна шкала для смуг діапазону: найнижчий мінімум і найвищий максимум за весь прогноз
+    let (range_min, range_max) = forecast.iter().flatten().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), day| {
+        (lo.min(day.temp_min), hi.max(day.temp_max))
+    });
+
     view! {
-        <section class="flex gap-3 overflow-x-auto no-scrollbar mb-8 pb-2">
+        <section class="flex gap-3 overflow-x-auto snap-x mb-8 pb-2" class:no-scrollbar=!long_range>
             {move || {
                 if let Some(ref days) = forecast {
                     days.iter().enumerate().map(|(idx, day)| {
                         let day_clone = day.clone();
                         view! {
                             <div 
-                                class="m3-card min-w-[140px] p-5 flex flex-col items-center text-center cursor-pointer hover:bg-[#252729] transition-colors"
+                                class="m3-card min-w-[168px] snap-start p-5 flex flex-col items-center text-center cursor-pointer hover:bg-[#252729] transition-colors"
                                 class:m3-card-active=move || selected_day.get() == idx
                                 on:click=move |_| {
                                     set_selected_day.set(idx);
@@ -226,11 +803,26 @@ fn WeeklyStrip(
                                 >
                                     {day_clone.day_name.clone()}
                                 </span>
-                                <i data-lucide={day_clone.icon.clone()} class={format!("w-10 h-10 mb-3 {}", day_clone.icon_color)}></i>
-                                <div class="flex gap-2">
-                                    <span class="text-lg font-bold">{format!("{:+}°", day_clone.temp_min)}</span>
-                                    <span class="text-lg text-gray-400">{format!("{:+}°", day_clone.temp_max)}</span>
+                                <i data-lucide={day_clone.icon.clone()} class={format!("w-10 h-10 mb-3 {}", day_clone.icon_color)} title=day_clone.description.clone()></i>
+                                <div class="w-full flex items-center gap-2">
+                                    <span class="text-sm text-gray-400" title="Мінімум">{move || units.get().temperature.format(day_clone.temp_min)}</span>
+                                    <TemperatureRangeBar
+                                        min=day_clone.temp_min
+                                        max=day_clone.temp_max
+                                        scale_min=range_min
+                                        scale_max=range_max
+                                    />
+                                    <span class="text-lg font-bold" title="Максимум">{move || units.get().temperature.format(day_clone.temp_max)}</span>
                                 </div>
+                                {day_clone.precipitation.probability.map(|probability| view! {
+                                    <span
+                                        class="mt-2 flex items-center gap-1 text-xs text-blue-300"
+                                        title=day_clone.precipitation.breakdown()
+                                    >
+                                        <i data-lucide="droplet" class="w-3 h-3"></i>
+                                        {format!("{}%", probability)}
+                                    </span>
+                                })}
                             </div>
                         }.into_view()
                     }).collect::<Vec<_>>()
@@ -244,31 +836,71 @@ fn WeeklyStrip(
     }
 }
 
+// Смуга діапазону температур дня на спільній шкалі прогнозу. Положення не залежить
+// від одиниць (перетворення лінійне), колір — від температури в °C
+#[component]
+fn TemperatureRangeBar(min: f64, max: f64, scale_min: f64, scale_max: f64) -> impl IntoView {
+    let span = (scale_max - scale_min).max(1.0);
+    // Навіть день без коливань температури має видиму позначку шириною 2%
+    let left = ((min - scale_min) / span * 100.0).clamp(0.0, 98.0);
+    let width = ((max - min) / span * 100.0).clamp(2.0, 100.0 - left);
+    let style = format!(
+        "left: {:.1}%; width: {:.1}%; background: linear-gradient(to right, {}, {})",
+        left,
+        width,
+        temperature_color(min),
+        temperature_color(max)
+    );
+
+    view! {
+        <div class="relative flex-1 h-1.5 rounded-full bg-[#333537]">
+            <div class="absolute inset-y-0 rounded-full" style=style></div>
+        </div>
+    }
+}
+
+// Колір температури в °C: від холодного синього до гарячого червоного
+fn temperature_color(celsius: f64) -> &'static str {
+    match celsius {
+        t if t < -10.0 => "#a5b4fc",
+        t if t < 0.0 => "#93c5fd",
+        t if t < 10.0 => "#67e8f9",
+        t if t < 18.0 => "#86efac",
+        t if t < 25.0 => "#fde047",
+        t if t < 30.0 => "#fdba74",
+        _ => "#f87171",
+    }
+}
+
 #[component]
 fn DetailedCard(
     data: WeatherData,
     selected_day: ReadSignal<usize>,
+    units: ReadSignal<Units>,
+    now: ReadSignal<i64>,
+    resolution: ReadSignal<HourlyResolution>,
+    set_resolution: WriteSignal<HourlyResolution>,
     forecast: Option<Vec<crate::api::weather::DayForecast>>,
 ) -> impl IntoView {
-    let hourly_times = vec!["0:00", "3:00", "9:00", "12:00", "15:00", "18:00", "21:00"];
-
-    // Визначаємо індекс поточного часу
-    let get_current_hour_index = move || -> usize {
-        let hour = get_current_hour();
-        match hour {
-            0..=2 => 0,   // 0:00
-            3..=8 => 1,   // 3:00
-            9..=11 => 2,  // 9:00
-            12..=14 => 3, // 12:00
-            15..=17 => 4, // 15:00
-            18..=20 => 5, // 18:00
-            _ => 6,       // 21:00
-        }
+    // Дата й година локації; таблиця перемальовується лише коли змінюється година
+    let offset = data.utc_offset_seconds;
+    let local_hour = create_memo(move |_| {
+        let local = LocalTime::from_unix(now.get(), offset);
+        (local.date(), local.hour)
+    });
+    // День чи ніч зараз: іконка поточних умов змінюється на сході й заході сонця
+    let is_day_now = {
+        let data = data.clone();
+        create_memo(move |_| data.is_day_at(now.get()))
     };
 
-    // Ініціалізуємо іконки після зміни дня
+    // Ініціалізуємо іконки після зміни дня, кроку таблиці, поточної години чи пори доби
     create_effect(move |_| {
-        selected_day.get(); // Відстежуємо зміни
+        // Відстежуємо зміни
+        selected_day.get();
+        resolution.get();
+        local_hour.get();
+        is_day_now.get();
         request_animation_frame(move || {
             createIcons();
         });
@@ -278,19 +910,45 @@ fn DetailedCard(
         <section class="m3-card p-6 md:p-10 mb-8">
             {move || {
                 let idx = selected_day.get();
+                let units_signal = units;
+                let units = units.get();
                 
                 if let Some(ref days) = forecast {
                     if let Some(day) = days.get(idx) {
                         let day_name = day.day_name.clone();
-                        let day_icon = day.icon.clone();
-                        let icon_color = day.icon_color.clone();
-                        let hourly_temps = day.hourly_temps.clone();
-                        let hourly_feels = day.hourly_feels.clone();
-                        let hourly_pressure = day.hourly_pressure.clone();
-                        let hourly_humidity = day.hourly_humidity.clone();
-                        let hourly_wind = day.hourly_wind.clone();
+                        let resolution = resolution.get();
+                        let hours = day.sample(resolution);
+                        // Погодинний режим ширший за картку — гортається горизонтально
+                        let scrollable = resolution == HourlyResolution::Hourly;
+                        // Далекі дні прогнозу мають дані лише кожні кілька годин
+                        let coarse_step = day.step_hours();
+                        // Поточна колонка — остання показана година, яка вже настала
+                        // за місцевим часом локації, а не браузера
+                        let (today, hour) = local_hour.get();
+                        let current_col = if day.date == today {
+                            hours.iter().rposition(|&i| {
+                                day.hourly_times.get(i).and_then(|t| hour_of(t)).is_some_and(|h| h <= hour)
+                            })
+                        } else {
+                            None
+                        };
+                        // Поточна година на графіку, який показує всі години дня
+                        let chart_current = if day.date == today {
+                            day.hourly_times.iter().rposition(|t| hour_of(t).is_some_and(|h| h <= hour))
+                        } else {
+                            None
+                        };
+                        // Сьогодні поруч із поточною температурою — поточні умови з урахуванням ночі
+                        let (day_icon, icon_color, description) = if day.date == today {
+                            let is_day = is_day_now.get();
+                            let (icon, color) = data.weather.icon(is_day);
+                            (icon.to_string(), color.to_string(), data.weather.description(is_day).to_string())
+                        } else {
+                            (day.icon.clone(), day.icon_color.clone(), day.description.clone())
+                        };
                         let sunrise = day.sunrise.clone();
                         let sunset = day.sunset.clone();
+                        let precipitation = day.precipitation;
                         
                         view! {
                             <div class="grid grid-cols-1 lg:grid-cols-12 gap-10">
@@ -299,7 +957,8 @@ fn DetailedCard(
                                         <i data-lucide={day_icon} class={format!("w-24 h-24 {}", icon_color)}></i>
                                         <div>
                                             <p class="text-gray-400">{day_name}</p>
-                                            <span class="text-7xl font-bold tracking-tighter">{format!("{:.0}°C", data.main.temp)}</span>
+                                            <p class="text-gray-300">{description}</p>
+                                            <span class="text-7xl font-bold tracking-tighter">{format!("{:.0}{}", units.temperature.convert(data.main.temp), units.temperature.label())}</span>
                                         </div>
                                     </div>
                                     <div class="space-y-3">
@@ -311,26 +970,51 @@ fn DetailedCard(
                                             <i data-lucide="sunset" class="w-5 h-5 text-purple-300"></i>
                                             <span>{format!("Захід: {}", sunset.unwrap_or_else(|| "Невідомо".to_string()))}</span>
                                         </div>
+                                        <div class="flex items-center gap-3 text-gray-300" title=precipitation.breakdown()>
+                                            <i data-lucide="cloud-rain" class="w-5 h-5 text-blue-300"></i>
+                                            <span>
+                                                {format!("Опади: {:.1} мм", precipitation.total)}
+                                                {precipitation.probability.map(|p| format!(", ймовірність {}%", p))}
+                                            </span>
+                                        </div>
                                     </div>
                                 </div>
 
-                                <div class="lg:col-span-8 overflow-x-auto no-scrollbar">
-                                    <table class="w-full text-left">
+                                <div
+                                    class="lg:col-span-8 overflow-x-auto"
+                                    class:no-scrollbar=!scrollable
+                                >
+                                    <div class="flex justify-end gap-2 mb-2 text-sm">
+                                        {(coarse_step > resolution.step()).then(|| view! {
+                                            <span class="mr-auto self-center text-gray-400">
+                                                {format!("Для цього дня дані є з кроком {} год", coarse_step)}
+                                            </span>
+                                        })}
+                                        {HourlyResolution::ALL.into_iter().map(|r| view! {
+                                            <button
+                                                class="m3-chip"
+                                                class:text-blue-200={r == resolution}
+                                                class:text-gray-400={r != resolution}
+                                                on:click=move |_| set_resolution.set(r)
+                                            >
+                                                {r.label()}
+                                            </button>
+                                        }).collect::<Vec<_>>()}
+                                    </div>
+                                    <table class="text-left" class:w-full=!scrollable class:w-max=scrollable>
                                         <thead>
                                             <tr class="text-gray-400 text-sm border-b border-[#43474E]">
                                                 <th class="py-4 font-normal min-w-[120px]">"Показник"</th>
-                                                {hourly_times.iter().enumerate().map(|(idx, time)| {
-                                                    let current_idx = get_current_hour_index();
-                                                    let is_today = selected_day.get() == 0;
-                                                    let is_current = is_today && idx == current_idx;
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let is_current = current_col == Some(col);
                                                     view! {
                                                         <th 
-                                                            class="py-4 font-normal text-center"
+                                                            class="py-4 px-2 font-normal text-center"
                                                             class:bg-opacity-10=move || is_current
                                                             class:text-blue-200=move || is_current
                                                             class=("bg-[#D1E4FF]", move || is_current)
                                                         >
-                                                            {*time}
+                                                            {day.hourly_times.get(i).cloned()}
                                                         </th>
                                                     }
                                                 }).collect::<Vec<_>>()}
@@ -338,92 +1022,113 @@ fn DetailedCard(
                                         </thead>
                                         <tbody class="text-sm">
                                             <tr class="border-b border-[#333537]">
-                                                <td class="py-4 text-gray-300">"Температура, °C"</td>
-                                                {hourly_temps.iter().enumerate().map(|(idx, temp)| {
-                                                    let current_idx = get_current_hour_index();
-                                                    let is_today = selected_day.get() == 0;
-                                                    let is_current = is_today && idx == current_idx;
+                                                <td class="py-4 text-gray-300">"Погода"</td>
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let icon = day.hourly_conditions.get(i).and_then(|c| {
+                                                        let code = c.weather_code?;
+                                                        let (icon, color) = get_weather_icon(code, c.is_day);
+                                                        let (description, _) = get_weather_description(code, c.is_day);
+                                                        Some((icon, color, description))
+                                                    });
                                                     view! {
-                                                        <td 
-                                                            class="text-center"
-                                                            class:font-bold=move || is_current
-                                                            class:text-white=move || is_current
-                                                            class:bg-opacity-10=move || is_current
-                                                            class=("bg-[#D1E4FF]", move || is_current)
-                                                        >
-                                                            {format!("{:+}°", temp)}
-                                                        </td>
+                                                        <HourCell is_current={current_col == Some(col)}>
+                                                            {icon.map(|(icon, color, description)| view! {
+                                                                <i data-lucide=icon class={format!("w-6 h-6 inline-block {}", color)} title=description></i>
+                                                            })}
+                                                        </HourCell>
+                                                    }
+                                                }).collect::<Vec<_>>()}
+                                            </tr>
+                                            <tr class="border-b border-[#333537]">
+                                                <td class="py-4 text-gray-300">{format!("Температура, {}", units.temperature.label())}</td>
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let value = day.hourly_temps.get(i).map(|&t| units.temperature.format(t));
+                                                    view! {
+                                                        <HourCell is_current={current_col == Some(col)}>{value}</HourCell>
                                                     }
                                                 }).collect::<Vec<_>>()}
                                             </tr>
                                             <tr class="border-b border-[#333537]">
                                                 <td class="py-4 text-gray-300">"Відчувається як"</td>
-                                                {hourly_feels.iter().enumerate().map(|(idx, feels)| {
-                                                    let current_idx = get_current_hour_index();
-                                                    let is_today = selected_day.get() == 0;
-                                                    let is_current = is_today && idx == current_idx;
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let value = day.hourly_feels.get(i).map(|&t| units.temperature.format(t));
                                                     view! {
-                                                        <td 
-                                                            class="text-center"
-                                                            class:bg-opacity-10=move || is_current
-                                                            class=("bg-[#D1E4FF]", move || is_current)
-                                                        >
-                                                            {format!("{:+}°", feels)}
-                                                        </td>
+                                                        <HourCell is_current={current_col == Some(col)}>{value}</HourCell>
                                                     }
                                                 }).collect::<Vec<_>>()}
                                             </tr>
                                             <tr class="border-b border-[#333537]">
-                                                <td class="py-4 text-gray-300">"Тиск, мм"</td>
-                                                {hourly_pressure.iter().enumerate().map(|(idx, pressure)| {
-                                                    let p = *pressure;
-                                                    let current_idx = get_current_hour_index();
-                                                    let is_today = selected_day.get() == 0;
-                                                    let is_current = is_today && idx == current_idx;
+                                                <td class="py-4 text-gray-300">{format!("Тиск, {}", units.pressure.label())}</td>
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let value = day.hourly_pressure.get(i).map(|&p| units.pressure.format(p));
                                                     view! {
-                                                        <td 
-                                                            class="text-center"
-                                                            class:bg-opacity-10=move || is_current
-                                                            class=("bg-[#D1E4FF]", move || is_current)
-                                                        >
-                                                            {p}
-                                                        </td>
+                                                        <HourCell is_current={current_col == Some(col)}>{value}</HourCell>
                                                     }
                                                 }).collect::<Vec<_>>()}
                                             </tr>
                                             <tr class="border-b border-[#333537]">
                                                 <td class="py-4 text-gray-300">"Вологість, %"</td>
-                                                {hourly_humidity.iter().enumerate().map(|(idx, humidity)| {
-                                                    let h = *humidity;
-                                                    let current_idx = get_current_hour_index();
-                                                    let is_today = selected_day.get() == 0;
-                                                    let is_current = is_today && idx == current_idx;
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let value = day.hourly_humidity.get(i).copied();
                                                     view! {
-                                                        <td 
-                                                            class="text-center"
-                                                            class:bg-opacity-10=move || is_current
-                                                            class=("bg-[#D1E4FF]", move || is_current)
-                                                        >
-                                                            {h}
-                                                        </td>
+                                                        <HourCell is_current={current_col == Some(col)}>{value}</HourCell>
+                                                    }
+                                                }).collect::<Vec<_>>()}
+                                            </tr>
+                                            <tr class="border-b border-[#333537]">
+                                                <td class="py-4 text-gray-300">"Опади, мм"</td>
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let precipitation = day.hourly_precipitation.get(i).copied();
+                                                    view! {
+                                                        <HourCell is_current={current_col == Some(col)}>
+                                                            {precipitation.map(|p| view! {
+                                                                <div title=p.breakdown()>{format!("{:.1}", p.total)}</div>
+                                                                {p.probability.map(|probability| view! {
+                                                                    <div class="text-xs text-blue-300">{format!("{}%", probability)}</div>
+                                                                })}
+                                                            })}
+                                                        </HourCell>
+                                                    }
+                                                }).collect::<Vec<_>>()}
+                                            </tr>
+                                            <tr class="border-b border-[#333537]">
+                                                <td class="py-4 text-gray-300">"Хмарність, %"</td>
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let value = day.hourly_conditions.get(i).and_then(|c| c.cloud_cover);
+                                                    view! {
+                                                        <HourCell is_current={current_col == Some(col)}>{value}</HourCell>
+                                                    }
+                                                }).collect::<Vec<_>>()}
+                                            </tr>
+                                            <tr class="border-b border-[#333537]">
+                                                <td class="py-4 text-gray-300">"УФ-індекс"</td>
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let value = day.hourly_conditions.get(i).and_then(|c| c.uv_index).map(|uv| format!("{:.0}", uv));
+                                                    view! {
+                                                        <HourCell is_current={current_col == Some(col)}>{value}</HourCell>
                                                     }
                                                 }).collect::<Vec<_>>()}
                                             </tr>
                                             <tr>
-                                                <td class="py-4 text-gray-300">"Вітер, м/с"</td>
-                                                {hourly_wind.iter().enumerate().map(|(idx, wind)| {
-                                                    let w = wind.clone();
-                                                    let current_idx = get_current_hour_index();
-                                                    let is_today = selected_day.get() == 0;
-                                                    let is_current = is_today && idx == current_idx;
+                                                <td class="py-4 text-gray-300">{format!("Вітер, {}", units.wind.label())}</td>
+                                                {hours.iter().enumerate().map(|(col, &i)| {
+                                                    let wind = day.hourly_wind.get(i).copied();
                                                     view! {
-                                                        <td 
-                                                            class="text-center"
-                                                            class:bg-opacity-10=move || is_current
-                                                            class=("bg-[#D1E4FF]", move || is_current)
-                                                        >
-                                                            {w}
-                                                        </td>
+                                                        <HourCell is_current={current_col == Some(col)}>
+                                                            {wind.map(|w| {
+                                                                // Стрілка повертається на кут напрямку, як і символи get_wind_direction
+                                                                let arrow_style = format!("display: inline-block; transform: rotate({}deg)", w.direction);
+                                                                view! {
+                                                                    <div class="flex items-center justify-center gap-1" title=format!("{}°", w.direction)>
+                                                                        <span>{units.wind.format(w.speed)}</span>
+                                                                        <span style=arrow_style>"↑"</span>
+                                                                    </div>
+                                                                    {w.gusts.map(|gusts| view! {
+                                                                        <div class="text-xs text-gray-400">{format!("пориви {}", units.wind.format(gusts))}</div>
+                                                                    })}
+                                                                }
+                                                            })}
+                                                        </HourCell>
                                                     }
                                                 }).collect::<Vec<_>>()}
                                             </tr>
@@ -431,6 +1136,9 @@ fn DetailedCard(
                                     </table>
                                 </div>
                             </div>
+                            <div class="mt-10">
+                                <HourlyChart day=day.clone() units=units_signal current=chart_current/>
+                            </div>
                         }.into_view()
                     } else {
                         view! {
@@ -451,8 +1159,79 @@ fn DetailedCard(
     }
 }
 
+// Поточні умови з оцінками: видимість, хмарність, УФ-індекс, точка роси
+#[component]
+fn CurrentConditions(data: WeatherData, units: ReadSignal<Units>) -> impl IntoView {
+    let visibility = data.visibility;
+    let clouds = data.clouds;
+    let uv_index = data.uv_index;
+    let dew_point = data.main.dew_point;
+
+    view! {
+        <section class="grid grid-cols-2 md:grid-cols-4 gap-3 mb-8">
+            <ConditionTile
+                icon="eye"
+                label="Видимість"
+                value=visibility.map(|m| format!("{:.1} км", m / 1000.0))
+                band=visibility.map(visibility_band)
+            />
+            <ConditionTile
+                icon="cloud"
+                label="Хмарність"
+                value=clouds.map(|c| format!("{}%", c))
+                band=clouds.map(cloud_band)
+            />
+            <ConditionTile
+                icon="sun"
+                label="УФ-індекс"
+                value=uv_index.map(|uv| format!("{:.1}", uv))
+                band=uv_index.map(uv_band)
+            />
+            <ConditionTile
+                icon="droplets"
+                label="Точка роси"
+                value=Signal::derive(move || dew_point.map(|t| format!("{:.0}{}", units.get().temperature.convert(t), units.get().temperature.label())))
+                band=dew_point.map(dew_point_band)
+            />
+        </section>
+    }
+}
+
+#[component]
+fn ConditionTile(
+    icon: &'static str,
+    label: &'static str,
+    #[prop(into)] value: MaybeSignal<Option<String>>,
+    band: Option<&'static str>,
+) -> impl IntoView {
+    view! {
+        <div class="m3-card p-5 flex items-center gap-4">
+            <i data-lucide=icon class="w-8 h-8 text-blue-200"></i>
+            <div>
+                <p class="text-sm text-gray-400">{label}</p>
+                <p class="text-xl font-medium">{move || value.get().unwrap_or_else(|| "—".to_string())}</p>
+                <p class="text-sm text-gray-400">{band.unwrap_or("немає даних")}</p>
+            </div>
+        </div>
+    }
+}
+
+// Комірка погодинної таблиці з підсвічуванням поточної години
 #[component]
-fn DescriptionsInfo(city: String) -> impl IntoView {
+fn HourCell(is_current: bool, children: Children) -> impl IntoView {
+    view! {
+        <td 
+            class="text-center px-2"
+            class:bg-opacity-10=move || is_current
+            class=("bg-[#D1E4FF]", move || is_current)
+        >
+            {children()}
+        </td>
+    }
+}
+
+#[component]
+fn DescriptionsInfo() -> impl IntoView {
     view! {
         /*<div class="grid grid-cols-1 md:grid-cols-2 gap-8 items-start">
             <div class="space-y-6">
@@ -548,3 +1327,77 @@ fn request_animation_frame(f: impl Fn() + 'static) {
         .expect("should register animation frame");
     closure.forget();
 }
+
+// Одноразовий запит координат через Geolocation API
+fn current_position(on_success: impl FnOnce(f64, f64) + 'static, on_error: impl Fn(String) + Clone + 'static) {
+    use wasm_bindgen::JsCast;
+
+    let Some(geolocation) = web_sys::window().and_then(|w| w.navigator().geolocation().ok()) else {
+        on_error("браузер не підтримує геолокацію".to_string());
+        return;
+    };
+
+    let success = wasm_bindgen::closure::Closure::once_into_js(move |position: web_sys::Position| {
+        let coords = position.coords();
+        on_success(coords.latitude(), coords.longitude());
+    });
+    let report = on_error.clone();
+    let failure = wasm_bindgen::closure::Closure::once_into_js(move |err: web_sys::PositionError| {
+        let message = match err.code() {
+            web_sys::PositionError::PERMISSION_DENIED => "доступ заборонено".to_string(),
+            web_sys::PositionError::TIMEOUT => "час очікування вичерпано".to_string(),
+            _ => err.message(),
+        };
+        report(message);
+    });
+
+    let options = web_sys::PositionOptions::new();
+    options.set_timeout(10_000);
+    options.set_maximum_age(300_000);
+
+    // Якщо запит навіть не вдалося надіслати, жоден колбек не буде викликано
+    if let Err(e) = geolocation.get_current_position_with_error_callback_and_options(
+        success.unchecked_ref(),
+        Some(failure.unchecked_ref()),
+        &options,
+    ) {
+        on_error(e.as_string().unwrap_or_else(|| "запит відхилено".to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_case_cyrillic_prefix_is_highlighted() {
+        assert_eq!(split_prefix("Черкаси", "чЕР"), ("Чер".to_string(), "каси".to_string()));
+        assert_eq!(split_prefix("Біла Церква", " БІЛА ц"), ("Біла Ц".to_string(), "ерква".to_string()));
+        assert_eq!(split_prefix("Ґалаґан", "ґАЛ"), ("Ґал".to_string(), "аґан".to_string()));
+    }
+
+    #[test]
+    fn no_match_leaves_name_whole() {
+        assert_eq!(split_prefix("Черкаси", "Чорно"), (String::new(), "Черкаси".to_string()));
+        assert_eq!(split_prefix("Черкаси", "Kyiv"), (String::new(), "Черкаси".to_string()));
+    }
+
+    #[test]
+    fn empty_query_highlights_nothing() {
+        assert_eq!(split_prefix("Київ", ""), (String::new(), "Київ".to_string()));
+        assert_eq!(split_prefix("Київ", "   "), (String::new(), "Київ".to_string()));
+    }
+
+    #[test]
+    fn query_longer_than_name_is_no_match() {
+        assert_eq!(split_prefix("Київ", "Київська"), (String::new(), "Київ".to_string()));
+        assert_eq!(split_prefix("Київ", "київ"), ("Київ".to_string(), String::new()));
+    }
+
+    // Літери, що при зміні регістру дають кілька символів, не повинні ламати зріз
+    #[test]
+    fn multi_char_case_mappings_do_not_panic() {
+        assert_eq!(split_prefix("Straße", "STRASSE"), (String::new(), "Straße".to_string()));
+        assert_eq!(split_prefix("ǅemal", "ǆe"), ("ǅe".to_string(), "mal".to_string()));
+    }
+}
@@ -1,11 +1,47 @@
-use leptos::*;
-use wasm_bindgen::prelude::*;
+//! Прогноз погоди Open-Meteo: геокодинг, поточна погода і прогноз до 16 днів.
+//!
+//! Модуль `api` не залежить від браузера і збирається нативно без фічі `web`:
+//!
+//! ```toml
+//! weather = { path = "...", default-features = false }
+//! ```
+//!
+//! Нативний `DefaultClient` (ureq) блокує потік на час запиту. У CLI його
+//! future виконує `pollster`; в async-сервісі обгорніть виклик у
+//! `spawn_blocking` або передайте власний асинхронний `HttpClient` у
+//! `WeatherClient::new`.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), weather::WeatherError> {
+//! let data = weather::WeatherClient::default().get_weather("Черкаси").await?;
+//! println!("{} {:.0}°C", data.name, data.main.temp);
+//! # Ok(())
+//! # }
+//! ```
 
+pub mod api;
+#[cfg(feature = "web")]
 mod components;
-mod api;
 
+pub use api::cache::{CachePolicy, CacheStore, CachedClient, MemoryStore, Spawner};
+pub use api::error::{Endpoint, WeatherError};
+pub use api::http::{AbortHandle, DefaultClient, HttpClient, HttpResponse};
+pub use api::time::LocalTime;
+pub use api::wmo::{Intensity, WeatherCode};
+pub use api::weather::{
+    build_day_forecasts, build_weather_data, get_weather, get_weather_for, get_weather_for_range, locate,
+    search_locations, ApiConfig, DayForecast, ForecastRange, GeoLocation, HourlyConditions, HourlyResolution, HourlyWind, Precipitation, WeatherClient, WeatherData,
+};
+
+#[cfg(feature = "web")]
+use leptos::*;
+#[cfg(feature = "web")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "web")]
 use components::weather_app::WeatherApp;
 
+#[cfg(feature = "web")]
 #[wasm_bindgen(start)]
 pub fn main() {
     console_error_panic_hook::set_once();